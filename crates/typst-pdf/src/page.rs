@@ -164,10 +164,13 @@ fn write_page(ctx: &mut PdfContext, i: usize) {
     }
 
     let mut annotations = page_writer.annotations();
-    for (dest, rect) in &page.links {
+    for (dest, alt, rect) in &page.links {
         let mut annotation = annotations.push();
         annotation.subtype(AnnotationType::Link).rect(*rect);
         annotation.border(0.0, 0.0, 0.0, None).flags(AnnotationFlags::PRINT);
+        if let Some(alt) = alt {
+            annotation.contents(TextStr(alt));
+        }
 
         let pos = match dest {
             Destination::Url(uri) => {
@@ -260,7 +263,7 @@ pub struct Page {
     /// Whether the page uses opacities.
     pub uses_opacities: bool,
     /// Links in the PDF coordinate system.
-    pub links: Vec<(Destination, Rect)>,
+    pub links: Vec<(Destination, Option<EcoString>, Rect)>,
     /// The page's PDF label.
     pub label: Option<PdfPageLabel>,
     /// The page's used resources
@@ -332,7 +335,7 @@ pub struct PageContext<'a, 'b> {
     saves: Vec<State>,
     bottom: f32,
     uses_opacities: bool,
-    links: Vec<(Destination, Rect)>,
+    links: Vec<(Destination, Option<EcoString>, Rect)>,
     /// Keep track of the resources being used in the page.
     pub resources: HashMap<PageResource, usize>,
 }
@@ -564,7 +567,7 @@ fn write_frame(ctx: &mut PageContext, frame: &Frame) {
             FrameItem::Shape(shape, _) => write_shape(ctx, pos, shape),
             FrameItem::Image(image, size, _) => write_image(ctx, x, y, image, *size),
             FrameItem::Meta(meta, size) => match meta {
-                Meta::Link(dest) => write_link(ctx, pos, dest, *size),
+                Meta::Link(dest, alt) => write_link(ctx, pos, dest, alt, *size),
                 Meta::Elem(_) => {}
                 Meta::Hide => {}
                 Meta::PageNumbering(_) => {}
@@ -782,7 +785,13 @@ fn write_image(ctx: &mut PageContext, x: f32, y: f32, image: &Image, size: Size)
 }
 
 /// Save a link for later writing in the annotations dictionary.
-fn write_link(ctx: &mut PageContext, pos: Point, dest: &Destination, size: Size) {
+fn write_link(
+    ctx: &mut PageContext,
+    pos: Point,
+    dest: &Destination,
+    alt: &Option<EcoString>,
+    size: Size,
+) {
     let mut min_x = Abs::inf();
     let mut min_y = Abs::inf();
     let mut max_x = -Abs::inf();
@@ -808,7 +817,7 @@ fn write_link(ctx: &mut PageContext, pos: Point, dest: &Destination, size: Size)
     let y2 = min_y.to_f32();
     let rect = Rect::new(x1, y1, x2, y2);
 
-    ctx.links.push((dest.clone(), rect));
+    ctx.links.push((dest.clone(), alt.clone(), rect));
 }
 
 fn to_pdf_line_cap(cap: LineCap) -> LineCapStyle {
@@ -1956,11 +1956,17 @@ impl<'a> WhileLoop<'a> {
 }
 
 node! {
-    /// A for loop: `for x in y { z }`.
+    /// A for loop: `for x in y { z }`, `for mut x in y { z }`.
     ForLoop
 }
 
 impl<'a> ForLoop<'a> {
+    /// Whether the pattern assigns to existing variables instead of binding
+    /// new ones.
+    pub fn assigns(self) -> bool {
+        self.0.children().any(|child| child.kind() == SyntaxKind::Mut)
+    }
+
     /// The pattern to assign to.
     pub fn pattern(self) -> Pattern<'a> {
         self.0.cast_first_match().unwrap_or_default()
@@ -2101,15 +2107,29 @@ impl<'a> ModuleInclude<'a> {
 }
 
 node! {
-    /// A break from a loop: `break`.
+    /// A break from a loop: `break`, `break if x > 5`.
     LoopBreak
 }
 
+impl<'a> LoopBreak<'a> {
+    /// The condition under which to break, if any.
+    pub fn condition(self) -> Option<Expr<'a>> {
+        self.0.cast_last_match()
+    }
+}
+
 node! {
-    /// A continue in a loop: `continue`.
+    /// A continue in a loop: `continue`, `continue if x == 2`.
     LoopContinue
 }
 
+impl<'a> LoopContinue<'a> {
+    /// The condition under which to continue, if any.
+    pub fn condition(self) -> Option<Expr<'a>> {
+        self.0.cast_last_match()
+    }
+}
+
 node! {
     /// A return from a function: `return`, `return x + 1`.
     FuncReturn
@@ -181,6 +181,8 @@ pub enum SyntaxKind {
     Include,
     /// The `as` keyword.
     As,
+    /// The `mut` keyword in front of a for loop's pattern.
+    Mut,
 
     /// Code.
     Code,
@@ -333,6 +335,7 @@ impl SyntaxKind {
                 | Self::Import
                 | Self::Include
                 | Self::As
+                | Self::Mut
         )
     }
 
@@ -437,6 +440,7 @@ impl SyntaxKind {
             Self::Import => "keyword `import`",
             Self::Include => "keyword `include`",
             Self::As => "keyword `as`",
+            Self::Mut => "keyword `mut`",
             Self::Code => "code",
             Self::Ident => "identifier",
             Self::Bool => "boolean",
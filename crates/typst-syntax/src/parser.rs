@@ -1103,6 +1103,7 @@ fn while_loop(p: &mut Parser) {
 fn for_loop(p: &mut Parser) {
     let m = p.marker();
     p.assert(SyntaxKind::For);
+    loop_mut(p);
     pattern(p);
     if p.at(SyntaxKind::Comma) {
         p.expected("keyword `in`");
@@ -1119,6 +1120,23 @@ fn for_loop(p: &mut Parser) {
     p.wrap(m, SyntaxKind::ForLoop);
 }
 
+/// Parses the `mut` in front of a for loop's pattern. It is only a keyword
+/// there, so that `mut` remains usable as a variable name.
+fn loop_mut(p: &mut Parser) {
+    if !p.at(SyntaxKind::Ident) || p.current_text() != "mut" {
+        return;
+    }
+
+    let mut lexer = p.lexer.clone();
+    let next = std::iter::from_fn(|| Some(lexer.next())).find(|kind| !kind.is_trivia());
+    if matches!(
+        next,
+        Some(SyntaxKind::Ident | SyntaxKind::LeftParen | SyntaxKind::Underscore)
+    ) {
+        p.convert(SyntaxKind::Mut);
+    }
+}
+
 fn module_import(p: &mut Parser) {
     let m = p.marker();
     p.assert(SyntaxKind::Import);
@@ -1167,15 +1185,25 @@ fn module_include(p: &mut Parser) {
 fn break_stmt(p: &mut Parser) {
     let m = p.marker();
     p.assert(SyntaxKind::Break);
+    loop_condition(p);
     p.wrap(m, SyntaxKind::LoopBreak);
 }
 
 fn continue_stmt(p: &mut Parser) {
     let m = p.marker();
     p.assert(SyntaxKind::Continue);
+    loop_condition(p);
     p.wrap(m, SyntaxKind::LoopContinue);
 }
 
+/// Parses the optional condition of a `break if` or `continue if`. The `if`
+/// must be on the same line, otherwise it starts a new conditional.
+fn loop_condition(p: &mut Parser) {
+    if !p.had_newline() && p.eat_if(SyntaxKind::If) {
+        code_expr(p);
+    }
+}
+
 fn return_stmt(p: &mut Parser) {
     let m = p.marker();
     p.assert(SyntaxKind::Return);
@@ -1546,6 +1574,12 @@ impl<'s> Parser<'s> {
         self.lexer.newline()
     }
 
+    /// Whether there is a line break between the previous non-trivia token
+    /// and the current one.
+    fn had_newline(&self) -> bool {
+        self.text[self.prev_end..self.current_start].contains(is_newline)
+    }
+
     fn column(&self, at: usize) -> usize {
         self.text[..at].chars().rev().take_while(|&c| !is_newline(c)).count()
     }
@@ -31,11 +31,7 @@ impl Eval for ast::DestructAssignment<'_> {
 
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
         let value = self.value().eval(vm)?;
-        destructure_impl(vm, self.pattern(), value, |vm, expr, value| {
-            let location = expr.access(vm)?;
-            *location = value;
-            Ok(())
-        })?;
+        assign(vm, self.pattern(), value)?;
         Ok(Value::None)
     }
 }
@@ -55,6 +51,19 @@ pub(crate) fn destructure(
     })
 }
 
+/// Destructures a value into the existing variables of a pattern.
+pub(crate) fn assign(
+    vm: &mut Vm,
+    pattern: ast::Pattern,
+    value: Value,
+) -> SourceResult<()> {
+    destructure_impl(vm, pattern, value, |vm, expr, value| {
+        let location = expr.access(vm)?;
+        *location = value;
+        Ok(())
+    })
+}
+
 /// Destruct the given value into the pattern and apply the function to each binding.
 fn destructure_impl<T>(
     vm: &mut Vm,
@@ -465,14 +465,19 @@ impl<'a> CapturesVisitor<'a> {
 
             // A for loop contains one or two bindings in its pattern. These are
             // active after the iterable is evaluated but before the body is
-            // evaluated.
+            // evaluated. With `mut`, the pattern instead refers to existing
+            // variables, which may be captured.
             Some(ast::Expr::For(expr)) => {
                 self.visit(expr.iter().to_untyped());
                 self.internal.enter();
 
                 let pattern = expr.pattern();
                 for ident in pattern.idents() {
-                    self.bind(ident);
+                    if expr.assigns() {
+                        self.capture(&ident, Scopes::get);
+                    } else {
+                        self.bind(ident);
+                    }
                 }
 
                 self.visit(expr.body().to_untyped());
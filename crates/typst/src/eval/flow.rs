@@ -1,14 +1,29 @@
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::diag::{bail, error, At, SourceDiagnostic, SourceResult};
-use crate::eval::{destructure, ops, Eval, Vm};
-use crate::foundations::{IntoValue, Value};
+use crate::diag::{bail, error, warning, At, SourceDiagnostic, SourceResult};
+use crate::engine::Engine;
+use crate::eval::{assign, destructure, ops, Eval, Vm};
+use crate::foundations::{dict, Content, Func, IntoValue, Scope, Value};
 use crate::syntax::ast::{self, AstNode};
 use crate::syntax::{Span, SyntaxKind, SyntaxNode};
 
-/// The maximum number of loop iterations.
+/// The maximum number of iterations of a `while` loop. `for` loops iterate over
+/// finite collections and are never capped.
 const MAX_ITERATIONS: usize = 10_000;
 
+/// What happens when a `while` loop exceeds the maximum number of iterations.
+///
+/// Configured through [`LibraryBuilder::with_loop_limit`](crate::LibraryBuilder::with_loop_limit).
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LoopLimit {
+    /// Abort compilation with an error.
+    #[default]
+    Error,
+    /// Stop the loop with a warning and continue with the output it produced
+    /// so far.
+    Warn,
+}
+
 /// A control flow event that occurred during evaluation.
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum FlowEvent {
@@ -64,6 +79,7 @@ impl Eval for ast::WhileLoop<'_> {
 
         let condition = self.condition();
         let body = self.body();
+        let progress = vm.engine.tracer.has_progress();
 
         while condition.eval(vm)?.cast::<bool>().at(condition.span())? {
             if i == 0
@@ -72,7 +88,16 @@ impl Eval for ast::WhileLoop<'_> {
             {
                 bail!(condition.span(), "condition is always true");
             } else if i >= MAX_ITERATIONS {
-                bail!(self.span(), "loop seems to be infinite");
+                if vm.engine.world.library().loop_limit == LoopLimit::Error {
+                    bail!(self.span(), "loop seems to be infinite");
+                }
+
+                vm.engine.tracer.warn(warning!(
+                    self.span(),
+                    "loop seems to be infinite";
+                    hint: "stopped after {MAX_ITERATIONS} iterations",
+                ));
+                break;
             }
 
             let value = body.eval(vm)?;
@@ -89,6 +114,9 @@ impl Eval for ast::WhileLoop<'_> {
             }
 
             i += 1;
+            if progress {
+                vm.engine.tracer.progress(i);
+            }
         }
 
         if flow.is_some() {
@@ -106,16 +134,53 @@ impl Eval for ast::ForLoop<'_> {
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
         let flow = vm.flow.take();
         let mut output = Value::None;
+        let progress = vm.engine.tracer.has_progress();
+        let assigns = self.assigns();
+        let records = vm.engine.tracer.records_iterations();
+
+        // Only bodies that refer to `loop` get the variable, so that others
+        // can still use an outer variable of that name.
+        let binds_loop = mentions(self.body().to_untyped(), "loop");
 
         macro_rules! iter {
             (for $pat:ident in $iter:expr) => {{
                 vm.scopes.enter();
 
                 #[allow(unused_parens)]
-                for value in $iter {
-                    destructure(vm, $pat, value.into_value())?;
+                let mut iter = $iter.into_iter().enumerate().peekable();
+                while let Some((i, value)) = iter.next() {
+                    if binds_loop {
+                        // Whether an iteration is the last one is known by
+                        // looking ahead by one item, so this works for any
+                        // kind of iterable.
+                        let info = dict! {
+                            "index" => i,
+                            "first" => i == 0,
+                            "last" => iter.peek().is_none(),
+                        };
+
+                        // A variable named `loop` that the pattern binds
+                        // takes precedence, while one the pattern assigns to
+                        // lives outside of the loop. The previous iteration's
+                        // `loop` is cleared so that the assignment reaches it.
+                        if assigns {
+                            vm.scopes.top = Scope::new();
+                            assign(vm, $pat, value.into_value())?;
+                            vm.scopes.top.define("loop", info);
+                        } else {
+                            vm.scopes.top.define("loop", info);
+                            destructure(vm, $pat, value.into_value())?;
+                        }
+                    } else if assigns {
+                        assign(vm, $pat, value.into_value())?;
+                    } else {
+                        destructure(vm, $pat, value.into_value())?;
+                    }
 
                     let body = self.body();
+                    if records {
+                        vm.engine.tracer.iteration(body.span(), i);
+                    }
                     let value = body.eval(vm)?;
                     output = ops::join(output, value).at(body.span())?;
 
@@ -128,6 +193,10 @@ impl Eval for ast::ForLoop<'_> {
                         Some(FlowEvent::Return(..)) => break,
                         None => {}
                     }
+
+                    if progress {
+                        vm.engine.tracer.progress(i + 1);
+                    }
                 }
 
                 vm.scopes.exit();
@@ -150,6 +219,26 @@ impl Eval for ast::ForLoop<'_> {
                 // Iterate over values of array.
                 iter!(for pattern in array);
             }
+            (_, Value::Args(args)) => {
+                // Iterate over positional arguments like over an array. Named
+                // arguments have no position, so they must be picked explicitly.
+                if args.items.iter().any(|arg| arg.name.is_some()) {
+                    bail!(
+                        self.iter().span(), "cannot loop over named arguments";
+                        hint: "use `args.pos()` or `args.named()` to pick one kind",
+                    );
+                }
+                iter!(for pattern in args.to_pos());
+            }
+            (_, Value::Content(content)) => {
+                // Iterate over the children of a sequence or the content
+                // itself if it is not a sequence.
+                let children: Vec<Content> = match content.to_sequence() {
+                    Some(children) => children.map(|child| (**child).clone()).collect(),
+                    None => vec![content],
+                };
+                iter!(for pattern in children);
+            }
             (ast::Pattern::Normal(_), _) => {
                 bail!(self.iter().span(), "cannot loop over {}", iter.ty());
             }
@@ -170,6 +259,9 @@ impl Eval for ast::LoopBreak<'_> {
     type Output = Value;
 
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
+        if !holds(vm, self.condition())? {
+            return Ok(Value::None);
+        }
         if vm.flow.is_none() {
             vm.flow = Some(FlowEvent::Break(self.span()));
         }
@@ -181,6 +273,9 @@ impl Eval for ast::LoopContinue<'_> {
     type Output = Value;
 
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
+        if !holds(vm, self.condition())? {
+            return Ok(Value::None);
+        }
         if vm.flow.is_none() {
             vm.flow = Some(FlowEvent::Continue(self.span()));
         }
@@ -200,6 +295,59 @@ impl Eval for ast::FuncReturn<'_> {
     }
 }
 
+/// Repeatedly applies `step` to a value until the result stops changing.
+///
+/// This backs the [`fixpoint`](crate::foundations::fixpoint) function. Like
+/// `while` loops, it gives up after [`MAX_ITERATIONS`] steps.
+pub(crate) fn fixpoint(
+    engine: &mut Engine,
+    init: Value,
+    step: &Func,
+    span: Span,
+) -> SourceResult<Value> {
+    let check = |value: &Value| {
+        if let Value::Func(_) | Value::Args(_) = value {
+            bail!(span, "cannot compare {} values to find a fixed point", value.ty());
+        }
+        Ok(())
+    };
+
+    let mut value = init;
+    check(&value)?;
+
+    for _ in 0..MAX_ITERATIONS {
+        let next = step.call(engine, [value.clone()])?;
+        check(&next)?;
+        if ops::equal(&next, &value) {
+            return Ok(next);
+        }
+        value = next;
+    }
+
+    if engine.world.library().loop_limit == LoopLimit::Error {
+        bail!(
+            span, "value never reached a fixed point";
+            hint: "stopped after {MAX_ITERATIONS} iterations",
+        );
+    }
+
+    engine.tracer.warn(warning!(
+        span,
+        "value never reached a fixed point";
+        hint: "stopped after {MAX_ITERATIONS} iterations",
+    ));
+
+    Ok(value)
+}
+
+/// Whether the optional condition of a `break if` or `continue if` holds.
+fn holds(vm: &mut Vm, condition: Option<ast::Expr>) -> SourceResult<bool> {
+    match condition {
+        Some(condition) => condition.eval(vm)?.cast::<bool>().at(condition.span()),
+        None => Ok(true),
+    }
+}
+
 /// Whether the expression always evaluates to the same value.
 fn is_invariant(expr: &SyntaxNode) -> bool {
     match expr.cast() {
@@ -221,3 +369,10 @@ fn can_diverge(expr: &SyntaxNode) -> bool {
     matches!(expr.kind(), SyntaxKind::Break | SyntaxKind::Return)
         || expr.children().any(can_diverge)
 }
+
+/// Whether the expression contains an identifier with the given name.
+fn mentions(expr: &SyntaxNode, name: &str) -> bool {
+    (matches!(expr.kind(), SyntaxKind::Ident | SyntaxKind::MathIdent)
+        && expr.text() == name)
+        || expr.children().any(|child| mentions(child, name))
+}
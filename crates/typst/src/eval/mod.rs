@@ -15,6 +15,7 @@ mod tracer;
 mod vm;
 
 pub use self::call::*;
+pub use self::flow::LoopLimit;
 pub use self::import::*;
 pub use self::tracer::*;
 pub use self::vm::*;
@@ -1,4 +1,6 @@
 use std::collections::HashSet;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
 
 use ecow::EcoVec;
 
@@ -15,6 +17,8 @@ pub struct Tracer {
     warnings_set: HashSet<u128>,
     delayed: EcoVec<SourceDiagnostic>,
     values: EcoVec<Value>,
+    progress: Option<(NonZeroUsize, Arc<dyn Fn(usize) + Send + Sync>)>,
+    iterations: Option<EcoVec<(Span, usize)>>,
 }
 
 impl Tracer {
@@ -46,6 +50,33 @@ impl Tracer {
     pub fn values(self) -> EcoVec<Value> {
         self.values
     }
+
+    /// Record the body span and index of each for loop iteration, such that
+    /// tooling can step through loops. Retrieve them via `iterations` later.
+    pub fn record_iterations(&mut self) {
+        self.iterations = Some(EcoVec::new());
+    }
+
+    /// Get the recorded loop iterations, in evaluation order.
+    pub fn iterations(&mut self) -> EcoVec<(Span, usize)> {
+        self.iterations.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    /// Install a hook that loops call every `interval` iterations with the
+    /// number of iterations completed so far.
+    ///
+    /// Reports go through the tracked tracer, so when memoized evaluation is
+    /// reused from the cache, they are replayed: the hook is called with the
+    /// same counts again, right away and without the loop running. The hook
+    /// should thus only be used for feedback, not to measure work.
+    pub fn with_progress(
+        mut self,
+        interval: NonZeroUsize,
+        hook: impl Fn(usize) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some((interval, Arc::new(hook)));
+        self
+    }
 }
 
 #[comemo::track]
@@ -79,4 +110,104 @@ impl Tracer {
             self.values.push(v);
         }
     }
+
+    /// Whether loop iterations are recorded.
+    pub fn records_iterations(&self) -> bool {
+        self.iterations.is_some()
+    }
+
+    /// Record that a for loop evaluated its body for the `index`-th time.
+    pub fn iteration(&mut self, body: Span, index: usize) {
+        if let Some(iterations) = &mut self.iterations {
+            iterations.push((body, index));
+        }
+    }
+
+    /// Whether a progress hook is installed.
+    pub fn has_progress(&self) -> bool {
+        self.progress.is_some()
+    }
+
+    /// Report that a loop completed `count` iterations. Calls the progress
+    /// hook if `count` is a multiple of its interval.
+    pub fn progress(&mut self, count: usize) {
+        if let Some((interval, hook)) = &self.progress {
+            if count % interval.get() == 0 {
+                hook(count);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use comemo::{Track, TrackedMut};
+
+    use super::*;
+
+    #[test]
+    fn test_progress_interval() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let mut tracer =
+            Tracer::new().with_progress(NonZeroUsize::new(10).unwrap(), move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            });
+
+        assert!(tracer.has_progress());
+        for count in 1..=35 {
+            tracer.progress(count);
+        }
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn test_progress_on_cache_hit() {
+        #[comemo::memoize]
+        fn run(mut tracer: TrackedMut<Tracer>, iterations: usize) {
+            for count in 1..=iterations {
+                tracer.progress(count);
+            }
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let mut tracer =
+            Tracer::new().with_progress(NonZeroUsize::new(2).unwrap(), move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            });
+
+        run(tracer.track_mut(), 5);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+        // The second call is a cache hit, which replays the reports.
+        run(tracer.track_mut(), 5);
+        assert_eq!(calls.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    fn test_progress_without_hook() {
+        let mut tracer = Tracer::new();
+        assert!(!tracer.has_progress());
+        tracer.progress(10);
+    }
+
+    #[test]
+    fn test_record_iterations() {
+        let mut tracer = Tracer::new();
+        tracer.iteration(Span::detached(), 0);
+        assert!(!tracer.records_iterations());
+        assert!(tracer.iterations().is_empty());
+
+        tracer.record_iterations();
+        assert!(tracer.records_iterations());
+        for i in 0..3 {
+            tracer.iteration(Span::detached(), i);
+        }
+        let indices: Vec<_> = tracer.iterations().iter().map(|&(_, i)| i).collect();
+        assert_eq!(indices, [0, 1, 2]);
+        assert!(tracer.iterations().is_empty());
+    }
 }
@@ -1,20 +1,20 @@
 use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
-use std::num::NonZeroI64;
+use std::num::NonZeroUsize;
 use std::ops::{Add, AddAssign};
 
 use ecow::{eco_format, EcoString, EcoVec};
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 
-use crate::diag::{At, SourceResult, StrResult};
+use crate::diag::{bail, At, Hint, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::eval::ops;
 use crate::foundations::{
-    cast, func, repr, scope, ty, Args, Bytes, CastInfo, FromValue, Func, IntoValue,
-    Reflect, Repr, Value, Version,
+    calc::Num, cast, func, repr, scope, ty, Args, Bytes, CastInfo, Dict, FromValue, Func,
+    IndexMap, IntoValue, Reflect, Repr, Str, Value, Version,
 };
-use crate::syntax::Span;
+use crate::syntax::{Span, Spanned};
 
 /// Create a new [`Array`] from values.
 #[macro_export]
@@ -143,6 +143,20 @@ impl Array {
 
         Ok(self.iter().cloned().cycle().take(count).collect())
     }
+
+    /// Flatten nested arrays up to the given depth or completely.
+    fn flatten_to(self, depth: Option<usize>) -> Array {
+        let mut flat = EcoVec::with_capacity(self.0.len());
+        for item in self {
+            match item {
+                Value::Array(nested) if depth != Some(0) => {
+                    flat.extend(nested.flatten_to(depth.map(|d| d - 1)));
+                }
+                item => flat.push(item),
+            }
+        }
+        flat.into()
+    }
 }
 
 #[scope]
@@ -280,6 +294,39 @@ impl Array {
         Ok(self.0[start..end].into())
     }
 
+    /// Returns the first `count` items of the array. If the array is shorter,
+    /// all of its items are returned.
+    ///
+    /// ```example
+    /// #for x in (1, 2, 3, 4).take(2) [#x ]
+    /// ```
+    #[func]
+    pub fn take(
+        &self,
+        /// The maximum number of items to return.
+        count: usize,
+    ) -> Array {
+        self.0.iter().take(count).cloned().collect()
+    }
+
+    /// Returns all overlapping windows of `size` consecutive items as arrays.
+    /// If the array has fewer than `size` items, there are no windows.
+    ///
+    /// ```example
+    /// #for (a, b) in (1, 3, 6, 10).windows(2) [#(b - a) ]
+    /// ```
+    #[func]
+    pub fn windows(
+        &self,
+        /// The number of items in each window.
+        size: NonZeroUsize,
+    ) -> Array {
+        self.0
+            .windows(size.get())
+            .map(|window| Value::Array(window.into()))
+            .collect()
+    }
+
     /// Whether the array contains the specified value.
     ///
     /// This method also has dedicated syntax: You can write `{2 in (1, 2, 3)}`
@@ -344,6 +391,10 @@ impl Array {
     /// `end` of the range. If you pass two, they describe the `start` and `end`
     /// of the range.
     ///
+    /// If any of the parameters is a float, the range consists of floats. Each
+    /// of them is computed as `start + i * step` for its index `i`, so
+    /// rounding errors don't accumulate over long ranges.
+    ///
     /// This function is available both in the array function's scope and
     /// globally.
     ///
@@ -352,7 +403,8 @@ impl Array {
     /// #range(2, 5) \
     /// #range(20, step: 4) \
     /// #range(21, step: 4) \
-    /// #range(5, 2, step: -1)
+    /// #range(5, 2, step: -1) \
+    /// #range(0.0, 1.0, step: 0.25)
     /// ```
     #[func]
     pub fn range(
@@ -361,30 +413,56 @@ impl Array {
         args: &mut Args,
         /// The start of the range (inclusive).
         #[external]
-        #[default]
-        start: i64,
+        #[default(Num::Int(0))]
+        start: Num,
         /// The end of the range (exclusive).
         #[external]
-        end: i64,
-        /// The distance between the generated numbers.
+        end: Num,
+        /// The distance between the generated numbers. Must not be zero.
+        #[external]
         #[named]
-        #[default(NonZeroI64::new(1).unwrap())]
-        step: NonZeroI64,
+        #[default(Num::Int(1))]
+        step: Num,
     ) -> SourceResult<Array> {
-        let first = args.expect::<i64>("end")?;
-        let (start, end) = match args.eat::<i64>()? {
+        let first = args.expect::<Num>("end")?;
+        let (start, end) = match args.eat::<Num>()? {
             Some(second) => (first, second),
-            None => (0, first),
+            None => (Num::Int(0), first),
         };
 
-        let step = step.get();
+        let Spanned { v: step, span } = args
+            .named::<Spanned<Num>>("step")?
+            .unwrap_or(Spanned::new(Num::Int(1), args.span));
 
-        let mut x = start;
         let mut array = Self::new();
+        match (start, end, step) {
+            (Num::Int(start), Num::Int(end), Num::Int(step)) => {
+                if step == 0 {
+                    bail!(span, "number must not be zero");
+                }
 
-        while x.cmp(&end) == 0.cmp(&step) {
-            array.push(x.into_value());
-            x += step;
+                let mut x = start;
+                while x.cmp(&end) == 0.cmp(&step) {
+                    array.push(x.into_value());
+                    x += step;
+                }
+            }
+            (start, end, step) => {
+                let (start, end, step) = (start.float(), end.float(), step.float());
+                if step == 0.0 {
+                    bail!(span, "number must not be zero");
+                } else if !start.is_finite() || !end.is_finite() || !step.is_finite() {
+                    bail!(args.span, "range must be finite");
+                }
+
+                for i in 0.. {
+                    let x = start + i as f64 * step;
+                    if (step > 0.0 && x >= end) || (step < 0.0 && x <= end) {
+                        break;
+                    }
+                    array.push(x.into_value());
+                }
+            }
         }
 
         Ok(array)
@@ -392,6 +470,12 @@ impl Array {
 
     /// Produces a new array with only the items from the original one for which
     /// the given function returns true.
+    ///
+    /// This is useful to skip items in a for loop without a `{continue}`.
+    ///
+    /// ```example
+    /// #for n in range(10).filter(calc.odd) [#n ]
+    /// ```
     #[func]
     pub fn filter(
         &self,
@@ -402,7 +486,12 @@ impl Array {
     ) -> SourceResult<Array> {
         let mut kept = EcoVec::new();
         for item in self.iter() {
-            if test.call(engine, [item.clone()])?.cast::<bool>().at(test.span())? {
+            let keep = test
+                .call(engine, [item.clone()])?
+                .cast::<bool>()
+                .hint(eco_format!("the function was called with {}", item.repr()))
+                .at(test.span())?;
+            if keep {
                 kept.push(item.clone())
             }
         }
@@ -576,6 +665,9 @@ impl Array {
     }
 
     /// Whether the given function returns `{true}` for any item in the array.
+    ///
+    /// The function isn't called for the items after the first one for which
+    /// it returns `{true}`.
     #[func]
     pub fn any(
         self,
@@ -594,6 +686,9 @@ impl Array {
     }
 
     /// Whether the given function returns `{true}` for all items in the array.
+    ///
+    /// The function isn't called for the items after the first one for which
+    /// it returns `{false}`.
     #[func]
     pub fn all(
         self,
@@ -612,17 +707,22 @@ impl Array {
     }
 
     /// Combine all nested arrays into a single flat one.
+    ///
+    /// Items that aren't arrays are kept as they are.
+    ///
+    /// ```example
+    /// #((1, 2), (3, (4, 5))).flatten() \
+    /// #((1, 2), (3, (4, 5))).flatten(depth: 1)
+    /// ```
     #[func]
-    pub fn flatten(self) -> Array {
-        let mut flat = EcoVec::with_capacity(self.0.len());
-        for item in self {
-            if let Value::Array(nested) = item {
-                flat.extend(nested.flatten());
-            } else {
-                flat.push(item);
-            }
-        }
-        flat.into()
+    pub fn flatten(
+        self,
+        /// How many levels of nesting to remove. By default, arrays are
+        /// flattened completely.
+        #[named]
+        depth: Option<usize>,
+    ) -> Array {
+        self.flatten_to(depth)
     }
 
     /// Return a new array with the same items, but in reverse order.
@@ -796,6 +896,48 @@ impl Array {
 
         Ok(Self(out))
     }
+
+    /// Groups the items of the array by a key.
+    ///
+    /// Returns a dictionary that maps each key to an array of the items with
+    /// that key. The groups are ordered by the first appearance of their key
+    /// and the items within each group keep their original order. Since
+    /// dictionary keys are strings, the key function must return a string.
+    ///
+    /// ```example
+    /// #let books = (
+    ///   (title: "Dune", genre: "sci-fi"),
+    ///   (title: "Emma", genre: "romance"),
+    ///   (title: "Solaris", genre: "sci-fi"),
+    /// )
+    ///
+    /// #for (genre, books) in books.group-by(book => book.genre) [
+    ///   - #genre: #books.map(book => book.title).join(", ")
+    /// ]
+    /// ```
+    #[func]
+    pub fn group_by(
+        self,
+        /// The engine.
+        engine: &mut Engine,
+        /// The function that determines the key of each item.
+        key: Func,
+    ) -> SourceResult<Dict> {
+        let mut groups = IndexMap::<Str, EcoVec<Value>>::new();
+        for item in self {
+            let group = key
+                .call(engine, [item.clone()])?
+                .cast::<Str>()
+                .hint(eco_format!("the function was called with {}", item.repr()))
+                .at(key.span())?;
+            groups.entry(group).or_default().push(item);
+        }
+
+        Ok(groups
+            .into_iter()
+            .map(|(group, items)| (group, Value::Array(items.into())))
+            .collect())
+    }
 }
 
 /// A value that can be cast to bytes.
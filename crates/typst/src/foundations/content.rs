@@ -12,14 +12,14 @@ use smallvec::smallvec;
 use crate::diag::{SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, func, scope, ty, Dict, Element, FromValue, Guard, IntoValue, Label,
+    elem, func, scope, ty, Array, Dict, Element, FromValue, Guard, IntoValue, Label,
     NativeElement, Recipe, Repr, Selector, Str, Style, Styles, Value,
 };
 use crate::introspection::{Location, Meta, MetaElem};
 use crate::layout::{Align, AlignElem, Axes, Length, MoveElem, PadElem, Rel, Sides};
 use crate::model::{Destination, EmphElem, StrongElem};
 use crate::syntax::Span;
-use crate::text::UnderlineElem;
+use crate::text::{SpaceElem, TextElem, UnderlineElem};
 use crate::util::fat;
 
 /// A piece of document content.
@@ -478,7 +478,13 @@ impl Content {
 
     /// Link the content somewhere.
     pub fn linked(self, dest: Destination) -> Self {
-        self.styled(MetaElem::set_data(smallvec![Meta::Link(dest)]))
+        self.linked_with_alt(dest, None)
+    }
+
+    /// Link the content somewhere, with an alternative description of the
+    /// link that exporters can use as its tooltip.
+    pub fn linked_with_alt(self, dest: Destination, alt: Option<EcoString>) -> Self {
+        self.styled(MetaElem::set_data(smallvec![Meta::Link(dest, alt)]))
     }
 
     /// Make the content linkable by `.linked(Destination::Location(loc))`.
@@ -571,6 +577,35 @@ impl Content {
         self.0.fields()
     }
 
+    /// The children of the content if it is a sequence, or just the content
+    /// itself otherwise. These are the values that a `for` loop over the
+    /// content visits.
+    ///
+    /// ```example
+    /// #let body = [Hello *World*]
+    /// #body.children().len() \
+    /// #body.children(skip-space: true).len()
+    /// ```
+    #[func]
+    pub fn children(
+        &self,
+        /// Whether to leave out children that consist only of whitespace, like
+        /// the spaces between words.
+        #[named]
+        #[default(false)]
+        skip_space: bool,
+    ) -> Array {
+        let children: Vec<Content> = match self.to_sequence() {
+            Some(children) => children.map(|child| (**child).clone()).collect(),
+            None => vec![self.clone()],
+        };
+        children
+            .into_iter()
+            .filter(|child| !(skip_space && is_whitespace(child)))
+            .map(IntoValue::into_value)
+            .collect()
+    }
+
     /// The location of the content. This is only available on content returned
     /// by [query]($query) or provided by a
     /// [show rule]($reference/styling/#show-rules), for other content it will
@@ -582,6 +617,14 @@ impl Content {
     }
 }
 
+/// Whether a child of a sequence consists only of whitespace.
+fn is_whitespace(content: &Content) -> bool {
+    content.is::<SpaceElem>()
+        || content
+            .to::<TextElem>()
+            .is_some_and(|elem| elem.text().chars().all(char::is_whitespace))
+}
+
 impl Default for Content {
     fn default() -> Self {
         Self::empty()
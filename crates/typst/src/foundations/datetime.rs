@@ -10,7 +10,8 @@ use time::{format_description, Month, PrimitiveDateTime};
 use crate::diag::{bail, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, func, repr, scope, ty, Dict, Duration, Repr, Smart, Str, Value,
+    cast, dict, func, repr, scope, ty, Array, Dict, Duration, IntoValue, Repr, Smart,
+    Str, Value,
 };
 use crate::World;
 
@@ -310,6 +311,88 @@ impl Datetime {
             .ok_or("unable to get the current date")?)
     }
 
+    /// Creates an array of datetimes from `start` up to, but excluding, `end`.
+    ///
+    /// The datetimes are spaced by the given `step`, which is either a
+    /// [duration]($duration) or a dictionary with a `months` key for calendar
+    /// month steps. When stepping by months, days that do not exist in the
+    /// target month are clamped to its last day. All arithmetic is performed
+    /// on plain calendar values, so there are no time zone or daylight saving
+    /// time shifts.
+    ///
+    /// ```example
+    /// #for date in datetime.range(
+    ///   datetime(year: 2023, month: 10, day: 2),
+    ///   datetime(year: 2023, month: 10, day: 9),
+    /// ) [
+    ///   #date.display("[weekday repr:short]")
+    /// ]
+    ///
+    /// #datetime.range(
+    ///   datetime(year: 2023, month: 1, day: 31),
+    ///   datetime(year: 2023, month: 5, day: 1),
+    ///   step: (months: 1),
+    /// ).map(d => d.display()).join(", ")
+    /// ```
+    #[func]
+    pub fn range(
+        /// The first datetime of the range (inclusive).
+        start: Datetime,
+        /// The end of the range (exclusive).
+        end: Datetime,
+        /// The distance between the generated datetimes. Can be negative to
+        /// count backwards.
+        #[named]
+        #[default(DateStep::Duration(time::Duration::days(1).into()))]
+        step: DateStep,
+    ) -> StrResult<Array> {
+        if start.kind() != end.kind() {
+            bail!("cannot create a range from {} to {}", start.kind(), end.kind());
+        }
+
+        let forward = match step {
+            DateStep::Duration(duration) if duration.is_zero() => {
+                bail!("step must not be zero")
+            }
+            DateStep::Duration(duration) => {
+                let duration: time::Duration = duration.into();
+                if matches!(start, Self::Date(_))
+                    && duration.whole_days() * 86400 != duration.whole_seconds()
+                {
+                    bail!("step must be a whole number of days for dates");
+                }
+                duration.is_positive()
+            }
+            DateStep::Months(0) => bail!("step must not be zero"),
+            DateStep::Months(_) if matches!(start, Self::Time(_)) => {
+                bail!("cannot step a time by months")
+            }
+            DateStep::Months(months) => months > 0,
+        };
+
+        let mut array = Array::new();
+        let mut current = start;
+        let mut i = 0;
+        while if forward { current < end } else { current > end } {
+            array.push(current.into_value());
+            i += 1;
+            let next = match step {
+                DateStep::Duration(duration) => current.checked_add(duration),
+                DateStep::Months(months) => {
+                    i.checked_mul(months).and_then(|n| start.add_months(n))
+                }
+            };
+            match next {
+                // Times wrap around at midnight, so stop instead of looping.
+                Some(next) if (next > current) == forward => current = next,
+                Some(_) => break,
+                None => bail!("range is too large"),
+            }
+        }
+
+        Ok(array)
+    }
+
     /// Displays the datetime in a specified format.
     ///
     /// Depending on whether you have defined just a date, a time or both, the
@@ -424,6 +507,62 @@ impl Datetime {
     }
 }
 
+impl Datetime {
+    /// Add a duration, returning `None` on overflow.
+    fn checked_add(self, rhs: Duration) -> Option<Self> {
+        let rhs: time::Duration = rhs.into();
+        Some(match self {
+            Self::Datetime(datetime) => Self::Datetime(datetime.checked_add(rhs)?),
+            Self::Date(date) => Self::Date(date.checked_add(rhs)?),
+            Self::Time(time) => Self::Time(time + rhs),
+        })
+    }
+
+    /// Move the date by a number of calendar months, clamping the day to the
+    /// length of the target month. Returns `None` for times and on overflow.
+    fn add_months(self, months: i64) -> Option<Self> {
+        let shift = |date: time::Date| {
+            let index = i64::from(date.year()) * 12 + i64::from(date.month() as u8 - 1);
+            let index = index.checked_add(months)?;
+            let year = i32::try_from(index.div_euclid(12)).ok()?;
+            let month = Month::try_from(index.rem_euclid(12) as u8 + 1).ok()?;
+            let day = date.day().min(time::util::days_in_year_month(year, month));
+            time::Date::from_calendar_date(year, month, day).ok()
+        };
+
+        Some(match self {
+            Self::Datetime(datetime) => {
+                Self::Datetime(datetime.replace_date(shift(datetime.date())?))
+            }
+            Self::Date(date) => Self::Date(shift(date)?),
+            Self::Time(_) => return None,
+        })
+    }
+}
+
+/// The distance between two consecutive datetimes in a range.
+#[derive(Copy, Clone, PartialEq, Hash)]
+pub enum DateStep {
+    /// A fixed duration.
+    Duration(Duration),
+    /// A number of calendar months.
+    Months(i64),
+}
+
+cast! {
+    DateStep,
+    self => match self {
+        Self::Duration(duration) => duration.into_value(),
+        Self::Months(months) => dict! { "months" => months }.into_value(),
+    },
+    v: Duration => Self::Duration(v),
+    mut v: Dict => {
+        let months = v.take("months")?.cast::<i64>()?;
+        v.finish(&["months"])?;
+        Self::Months(months)
+    },
+}
+
 impl Repr for Datetime {
     fn repr(&self) -> EcoString {
         let year = self.year().map(|y| eco_format!("year: {}", (y as i64).repr()));
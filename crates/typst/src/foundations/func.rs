@@ -196,6 +196,41 @@ impl Func {
         self.params()?.iter().find(|param| param.name == name)
     }
 
+    /// Whether the function's signature allows calling it with exactly
+    /// `count` positional arguments and no named ones.
+    ///
+    /// Returns `None` if that can't be told upfront, which is the case for
+    /// functions with pre-applied arguments.
+    pub fn accepts_positional(&self, count: usize) -> Option<bool> {
+        match &self.repr {
+            Repr::Native(_) | Repr::Element(_) => {
+                let params = self.params()?;
+                let positional = params.iter().filter(|p| p.positional && !p.variadic);
+                let required = positional.clone().filter(|p| p.required).count();
+                let variadic = params.iter().any(|p| p.positional && p.variadic);
+                let named = params.iter().any(|p| p.required && !p.positional);
+                Some(
+                    !named
+                        && count >= required
+                        && (variadic || count <= positional.count()),
+                )
+            }
+            Repr::Closure(closure) => {
+                let closure = closure.node.cast::<ast::Closure>().unwrap();
+                let (mut positional, mut sink) = (0, false);
+                for param in closure.params().children() {
+                    match param {
+                        ast::Param::Pos(_) => positional += 1,
+                        ast::Param::Named(_) => {}
+                        ast::Param::Sink(_) => sink = true,
+                    }
+                }
+                Some(count == positional || (sink && count > positional))
+            }
+            Repr::With(_) => None,
+        }
+    }
+
     /// Get details about the function's return type.
     pub fn returns(&self) -> Option<&'static CastInfo> {
         static CONTENT: Lazy<CastInfo> =
@@ -73,7 +73,7 @@ use ecow::EcoString;
 use crate::diag::{bail, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::eval::EvalMode;
-use crate::syntax::Spanned;
+use crate::syntax::{Span, Spanned};
 
 /// Foundational types and functions.
 ///
@@ -108,6 +108,7 @@ pub(super) fn define(global: &mut Scope, inputs: Dict) {
     global.define_func::<panic>();
     global.define_func::<assert>();
     global.define_func::<eval>();
+    global.define_func::<fixpoint>();
     global.define_func::<style>();
     global.define_module(calc::module());
     global.define_module(sys::module(inputs));
@@ -293,3 +294,36 @@ pub fn eval(
     }
     crate::eval::eval_string(engine.world, &text, span, mode, scope)
 }
+
+/// Repeatedly applies a function to a value until it stops changing.
+///
+/// Starting from `init`, the function is called with the value produced by the
+/// previous call. As soon as a call returns a value equal to its input, that
+/// value is returned. If no fixed point is reached after 10,000 steps, the
+/// function fails with an error.
+///
+/// # Example
+/// ```example
+/// // Newton's method for the square root of two.
+/// #fixpoint(1.0, x => calc.round((x + 2 / x) / 2, digits: 8))
+///
+/// // Collapse repeated spaces.
+/// #fixpoint("a    b", s => s.replace("  ", " "))
+/// ```
+#[func(title = "Fixed Point")]
+pub fn fixpoint(
+    /// The engine.
+    engine: &mut Engine,
+    /// The callsite span.
+    span: Span,
+    /// The value to start from.
+    init: Value,
+    /// The function to apply. It receives the previous value and returns the
+    /// next one.
+    ///
+    /// Values are compared with `==`. Functions and arguments can't be
+    /// meaningfully compared, so they are not allowed as values.
+    step: Func,
+) -> SourceResult<Value> {
+    crate::eval::fixpoint(engine, init, &step, span)
+}
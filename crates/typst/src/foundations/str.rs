@@ -239,6 +239,26 @@ impl Str {
         Ok(self.0[start..end].into())
     }
 
+    /// Returns the first `count` grapheme clusters of the string. If the
+    /// string is shorter, the whole string is returned.
+    ///
+    /// ```example
+    /// #for c in "Typst".take(3) [#c ]
+    /// ```
+    #[func]
+    pub fn take(
+        &self,
+        /// The maximum number of grapheme clusters to return.
+        count: usize,
+    ) -> Str {
+        let end = self
+            .as_str()
+            .grapheme_indices(true)
+            .nth(count)
+            .map_or(self.len(), |(i, _)| i);
+        self.0[..end].into()
+    }
+
     /// Returns the grapheme clusters of the string as an array of substrings.
     #[func]
     pub fn clusters(&self) -> Array {
@@ -208,6 +208,26 @@ impl Introspector {
         }
     }
 
+    /// The number of elements matching the selector that appear before the
+    /// given location, including the element at the location if `inclusive`
+    /// is set.
+    ///
+    /// This is equivalent to the length of a `before` query, but doesn't
+    /// collect and cache the preceding elements for each distinct location.
+    pub fn count_before(
+        &self,
+        selector: &Selector,
+        end: Location,
+        inclusive: bool,
+    ) -> usize {
+        let list = self.query(selector);
+        let Some(end) = self.get(&end) else { return list.len() };
+        match self.binary_search(&list, end) {
+            Ok(i) => i + inclusive as usize,
+            Err(i) => i,
+        }
+    }
+
     /// Query for a unique element with the label.
     pub fn query_label(&self, label: Label) -> StrResult<&Prehashed<Content>> {
         let indices = self.labels.get(&label).ok_or_else(|| {
@@ -74,8 +74,9 @@ impl Behave for MetaElem {
 #[ty]
 #[derive(Clone, PartialEq, Hash)]
 pub enum Meta {
-    /// An internal or external link to a destination.
-    Link(Destination),
+    /// An internal or external link to a destination, optionally with an
+    /// alternative description of the link.
+    Link(Destination, Option<EcoString>),
     /// An identifiable element that produces something within the area this
     /// metadata is attached to.
     Elem(Content),
@@ -96,7 +97,7 @@ cast! {
 impl Debug for Meta {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
-            Self::Link(dest) => write!(f, "Link({dest:?})"),
+            Self::Link(dest, _) => write!(f, "Link({dest:?})"),
             Self::Elem(content) => write!(f, "Elem({:?})", content.func()),
             Self::PageNumbering(value) => write!(f, "PageNumbering({value:?})"),
             Self::PdfPageLabel(label) => write!(f, "PdfPageLabel({label:?})"),
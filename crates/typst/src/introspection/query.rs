@@ -10,7 +10,9 @@ use crate::introspection::Location;
 /// function.
 ///
 /// You can get the location of the elements returned by `query` with
-/// [`location`]($content.location).
+/// [`location`]($content.location). The elements are always returned in the
+/// order in which they appear in the document, no matter how the selector is
+/// composed, so a `for` loop over them visits them in document order.
 ///
 /// # Finding elements
 /// In the example below, we create a custom page header that displays the text
@@ -316,10 +316,7 @@ impl State {
         location: Location,
     ) -> SourceResult<Value> {
         let sequence = self.sequence(engine)?;
-        let offset = engine
-            .introspector
-            .query(&self.selector().before(location.into(), true))
-            .len();
+        let offset = engine.introspector.count_before(&self.selector(), location, true);
         Ok(sequence[offset].clone())
     }
 
@@ -65,7 +65,7 @@ use typst_timing::{timed, TimingScope};
 
 use crate::diag::{warning, FileResult, SourceDiagnostic, SourceResult};
 use crate::engine::{Engine, Route};
-use crate::eval::Tracer;
+use crate::eval::{LoopLimit, Tracer};
 use crate::foundations::{
     Array, Bytes, Content, Datetime, Dict, Module, Scope, StyleChain, Styles,
 };
@@ -254,6 +254,8 @@ pub struct Library {
     /// The default style properties (for page size, font selection, and
     /// everything else configurable via set and show rules).
     pub styles: Styles,
+    /// What happens when a loop exceeds the maximum number of iterations.
+    pub loop_limit: LoopLimit,
 }
 
 impl Library {
@@ -276,6 +278,7 @@ impl Default for Library {
 #[derive(Debug, Clone, Default)]
 pub struct LibraryBuilder {
     inputs: Option<Dict>,
+    loop_limit: LoopLimit,
 }
 
 impl LibraryBuilder {
@@ -285,12 +288,24 @@ impl LibraryBuilder {
         self
     }
 
+    /// Configure what happens when a loop exceeds the maximum number of
+    /// iterations. By default, compilation fails with an error.
+    pub fn with_loop_limit(mut self, loop_limit: LoopLimit) -> Self {
+        self.loop_limit = loop_limit;
+        self
+    }
+
     /// Consumes the builder and returns a `Library`.
     pub fn build(self) -> Library {
         let math = math::module();
         let inputs = self.inputs.unwrap_or_default();
         let global = global(math.clone(), inputs);
-        Library { global, math, styles: Styles::new() }
+        Library {
+            global,
+            math,
+            styles: Styles::new(),
+            loop_limit: self.loop_limit,
+        }
     }
 }
 
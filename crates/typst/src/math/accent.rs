@@ -2,11 +2,12 @@ use unicode_math_class::MathClass;
 
 use crate::diag::{bail, SourceResult};
 use crate::foundations::{cast, elem, Content, NativeElement, Resolve, Smart, Value};
-use crate::layout::{Em, Frame, Length, Point, Rel, Size};
+use crate::layout::{Abs, Em, Frame, Length, Point, Rel, Size};
 use crate::math::{
     FrameFragment, GlyphFragment, LayoutMath, MathContext, MathFragment, Scaled,
 };
 use crate::symbols::Symbol;
+use crate::syntax::Span;
 use crate::text::TextElem;
 
 /// How much the accent can be shorter than the base.
@@ -69,60 +70,73 @@ impl LayoutMath for AccentElem {
         let base = ctx.layout_fragment(self.base())?;
         ctx.unstyle();
 
-        // Preserve class to preserve automatic spacing.
-        let base_class = base.class().unwrap_or(MathClass::Normal);
-        let base_attach = base.accent_attach();
-
         let width = self
             .size(ctx.styles())
             .unwrap_or(Rel::one())
             .resolve(ctx.styles())
             .relative_to(base.width());
 
-        // Forcing the accent to be at least as large as the base makes it too
-        // wide in many case.
-        let Accent(c) = self.accent();
-        let glyph = GlyphFragment::new(ctx, *c, self.span());
-        let short_fall = ACCENT_SHORT_FALL.scaled(ctx);
-        let variant = glyph.stretch_horizontal(ctx, width, short_fall);
-        let accent = variant.frame;
-        let accent_attach = variant.accent_attach;
-
-        // Descent is negative because the accent's ink bottom is above the
-        // baseline. Therefore, the default gap is the accent's negated descent
-        // minus the accent base height. Only if the base is very small, we need
-        // a larger gap so that the accent doesn't move too low.
-        let accent_base_height = scaled!(ctx, accent_base_height);
-        let gap = -accent.descent() - base.height().min(accent_base_height);
-        let size = Size::new(base.width(), accent.height() + gap + base.height());
-        let accent_pos = Point::with_x(base_attach - accent_attach);
-        let base_pos = Point::with_y(accent.height() + gap);
-        let baseline = base_pos.y + base.ascent();
-        let base_italics_correction = base.italics_correction();
-        let base_text_like = base.is_text_like();
-
-        let base_ascent = match &base {
-            MathFragment::Frame(frame) => frame.base_ascent,
-            _ => base.ascent(),
-        };
-
-        let mut frame = Frame::soft(size);
-        frame.set_baseline(baseline);
-        frame.push_frame(accent_pos, accent);
-        frame.push_frame(base_pos, base.into_frame());
-        ctx.push(
-            FrameFragment::new(ctx, frame)
-                .with_class(base_class)
-                .with_base_ascent(base_ascent)
-                .with_italics_correction(base_italics_correction)
-                .with_accent_attach(base_attach)
-                .with_text_like(base_text_like),
-        );
+        let fragment = ctx.layout_accent(base, *self.accent(), width, self.span());
+        ctx.push(fragment);
 
         Ok(())
     }
 }
 
+/// Attach an accent over a base, stretched to roughly the given width.
+///
+/// The accent is placed at the base's top accent attachment point, which falls
+/// back to the horizontal center for glyphs without one.
+pub(super) fn layout_accent(
+    ctx: &MathContext,
+    base: MathFragment,
+    accent: Accent,
+    width: Abs,
+    span: Span,
+) -> FrameFragment {
+    // Preserve class to preserve automatic spacing.
+    let base_class = base.class().unwrap_or(MathClass::Normal);
+    let base_attach = base.accent_attach();
+
+    // Forcing the accent to be at least as large as the base makes it too
+    // wide in many case.
+    let Accent(c) = accent;
+    let glyph = GlyphFragment::new(ctx, c, span);
+    let short_fall = ACCENT_SHORT_FALL.scaled(ctx);
+    let variant = glyph.stretch_horizontal(ctx, width, short_fall);
+    let accent = variant.frame;
+    let accent_attach = variant.accent_attach;
+
+    // Descent is negative because the accent's ink bottom is above the
+    // baseline. Therefore, the default gap is the accent's negated descent
+    // minus the accent base height. Only if the base is very small, we need
+    // a larger gap so that the accent doesn't move too low.
+    let accent_base_height = scaled!(ctx, accent_base_height);
+    let gap = -accent.descent() - base.height().min(accent_base_height);
+    let size = Size::new(base.width(), accent.height() + gap + base.height());
+    let accent_pos = Point::with_x(base_attach - accent_attach);
+    let base_pos = Point::with_y(accent.height() + gap);
+    let baseline = base_pos.y + base.ascent();
+    let base_italics_correction = base.italics_correction();
+    let base_text_like = base.is_text_like();
+
+    let base_ascent = match &base {
+        MathFragment::Frame(frame) => frame.base_ascent,
+        _ => base.ascent(),
+    };
+
+    let mut frame = Frame::soft(size);
+    frame.set_baseline(baseline);
+    frame.push_frame(accent_pos, accent);
+    frame.push_frame(base_pos, base.into_frame());
+    FrameFragment::new(ctx, frame)
+        .with_class(base_class)
+        .with_base_ascent(base_ascent)
+        .with_italics_correction(base_italics_correction)
+        .with_accent_attach(base_attach)
+        .with_text_like(base_text_like)
+}
+
 /// An accent character.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Accent(char);
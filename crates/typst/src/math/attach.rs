@@ -1,7 +1,7 @@
 use unicode_math_class::MathClass;
 
 use crate::diag::SourceResult;
-use crate::foundations::{elem, Content, StyleChain};
+use crate::foundations::{elem, Content, Smart, StyleChain};
 use crate::layout::{Abs, Frame, Point, Size};
 use crate::math::{
     FrameFragment, LayoutMath, MathContext, MathFragment, MathSize, Scaled,
@@ -46,6 +46,25 @@ pub struct AttachElem {
 
     /// The bottom-right attachment (after the base).
     pub br: Option<Content>,
+
+    /// Whether large operators like sums place their top and bottom
+    /// attachments as limits, regardless of whether the equation is displayed
+    /// as a block.
+    ///
+    /// By default, most large operators use limits in block equations only
+    /// and integrals never do. Set this to `{true}` to always use limits or
+    /// to `{false}` to always use scripts. Wrapping a base in `{limits()}` or
+    /// `{scripts()}` still takes precedence.
+    ///
+    /// ```example
+    /// #set math.attach(limits: true)
+    /// Inline: $sum_(i=0)^n i$
+    ///
+    /// #set math.attach(limits: false)
+    /// $ sum_(i=0)^n i $
+    /// ```
+    #[default(Smart::Auto)]
+    pub limits: Smart<bool>,
 }
 
 impl LayoutMath for AttachElem {
@@ -72,6 +91,10 @@ impl LayoutMath for AttachElem {
         let b = layout_attachment(ctx, Self::b)?;
         ctx.unstyle();
 
+        if [&tl, &tr, &bl, &br].iter().all(|script| script.is_none()) {
+            return ctx.layout_limits(base, t, b);
+        }
+
         let limits = base.limits().active(ctx);
         let (t, tr) = if limits || tr.is_some() { (t, tr) } else { (None, t) };
         let (b, br) = if limits || br.is_some() { (b, br) } else { (None, b) };
@@ -212,11 +235,29 @@ impl Limits {
         }
     }
 
+    /// The limit configuration if the given character is the base in the
+    /// current style, taking the `limits` style of attachments into account
+    /// for large operators.
+    pub fn for_char_in(c: char, ctx: &MathContext) -> Self {
+        match AttachElem::limits_in(ctx.styles()) {
+            Smart::Custom(forced)
+                if unicode_math_class::class(c) == Some(MathClass::Large) =>
+            {
+                if forced {
+                    Limits::Always
+                } else {
+                    Limits::Never
+                }
+            }
+            _ => Self::for_char(c),
+        }
+    }
+
     /// Whether limits should be displayed in this context
     pub fn active(&self, ctx: &MathContext) -> bool {
         match self {
             Self::Always => true,
-            Self::Display => ctx.style.size == MathSize::Display,
+            Self::Display => ctx.current_size() == MathSize::Display,
             Self::Never => false,
         }
     }
@@ -229,7 +270,7 @@ macro_rules! measure {
 }
 
 /// Layout the attachments.
-fn layout_attachments(
+pub(super) fn layout_attachments(
     ctx: &mut MathContext,
     base: MathFragment,
     [tl, t, tr, bl, b, br]: [Option<MathFragment>; 6],
@@ -366,7 +407,7 @@ fn compute_shifts_up_and_down(
     base: &MathFragment,
     [tl, tr, bl, br]: [&Option<MathFragment>; 4],
 ) -> (Abs, Abs) {
-    let sup_shift_up = if ctx.style.cramped {
+    let sup_shift_up = if ctx.is_cramped() {
         scaled!(ctx, superscript_shift_up_cramped)
     } else {
         scaled!(ctx, superscript_shift_up)
@@ -375,7 +416,7 @@ fn compute_shifts_up_and_down(
     let sup_bottom_min = scaled!(ctx, superscript_bottom_min);
     let sup_bottom_max_with_sub = scaled!(ctx, superscript_bottom_max_with_subscript);
     let sup_drop_max = scaled!(ctx, superscript_baseline_drop_max);
-    let gap_min = scaled!(ctx, sub_superscript_gap_min);
+    let gap_min = ctx.sub_superscript_gap_min();
     let sub_shift_down = scaled!(ctx, subscript_shift_down);
     let sub_top_max = scaled!(ctx, subscript_top_max);
     let sub_drop_min = scaled!(ctx, subscript_baseline_drop_min);
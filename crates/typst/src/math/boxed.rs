@@ -0,0 +1,61 @@
+use crate::diag::SourceResult;
+use crate::foundations::{elem, Content, NativeElement, Smart};
+use crate::layout::{Abs, Em, Length, Sides};
+use crate::math::{LayoutMath, MathContext};
+use crate::text::TextElem;
+use crate::visualize::{FixedStroke, Stroke};
+
+/// Draws a rectangular frame around a part of an equation, like `\boxed` in
+/// LaTeX.
+///
+/// The framed part stays on the baseline and is spaced like an ordinary
+/// symbol.
+///
+/// ```example
+/// $ E = boxed(m c^2) $
+/// ```
+#[elem(LayoutMath)]
+pub struct BoxedElem {
+    /// The content to frame.
+    #[required]
+    pub body: Content,
+
+    /// How much space to leave between the content and the frame on each
+    /// side.
+    ///
+    /// ```example
+    /// $ boxed(x, inset: #0pt) boxed(x, inset: #0.5em) $
+    /// ```
+    #[resolve]
+    #[default(Em::new(0.25).into())]
+    pub inset: Length,
+
+    /// How to [stroke]($stroke) the frame.
+    ///
+    /// ```example
+    /// $ boxed(a + b, stroke: #(paint: blue, dash: "dashed")) $
+    /// ```
+    #[resolve]
+    #[fold]
+    #[default(Stroke {
+        thickness: Smart::Custom(Abs::pt(0.5)),
+        ..Default::default()
+    })]
+    pub stroke: Stroke,
+}
+
+impl LayoutMath for BoxedElem {
+    #[typst_macros::time(name = "math.boxed", span = self.span())]
+    fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
+        let styles = ctx.styles();
+        let inset = Sides::splat(self.inset(styles));
+        let stroke = self.stroke(styles).unwrap_or(FixedStroke {
+            paint: TextElem::fill_in(styles).as_decoration(),
+            ..Default::default()
+        });
+
+        let fragment = ctx.layout_boxed(self.body(), inset, Some(stroke), self.span())?;
+        ctx.push(fragment);
+        Ok(())
+    }
+}
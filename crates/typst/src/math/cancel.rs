@@ -1,11 +1,9 @@
-use unicode_math_class::MathClass;
-
 use crate::diag::{At, SourceResult};
 use crate::foundations::{cast, elem, Content, Func, NativeElement, Resolve, Smart};
 use crate::layout::{
     Abs, Angle, Frame, FrameItem, Length, Point, Ratio, Rel, Size, Transform,
 };
-use crate::math::{FrameFragment, LayoutMath, MathContext};
+use crate::math::{LayoutMath, MathContext};
 use crate::syntax::Span;
 use crate::text::TextElem;
 use crate::visualize::{FixedStroke, Geometry, Stroke};
@@ -109,16 +107,8 @@ impl LayoutMath for CancelElem {
     #[typst_macros::time(name = "math.cancel", span = self.span())]
     fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
         let body = ctx.layout_fragment(self.body())?;
-        // Preserve properties of body.
-        let body_class = body.class().unwrap_or(MathClass::Special);
-        let body_italics = body.italics_correction();
-        let body_attach = body.accent_attach();
-        let body_text_like = body.is_text_like();
-
-        let mut body = body.into_frame();
 
         let styles = ctx.styles();
-        let body_size = body.size();
         let span = self.span();
         let length = self.length(styles).resolve(styles);
 
@@ -129,44 +119,26 @@ impl LayoutMath for CancelElem {
 
         let invert = self.inverted(styles);
         let cross = self.cross(styles);
-        let angle = self.angle(styles);
-
-        let invert_first_line = !cross && invert;
-        let first_line = draw_cancel_line(
-            ctx,
-            length,
-            stroke.clone(),
-            invert_first_line,
-            &angle,
-            body_size,
-            span,
-        )?;
-
-        // The origin of our line is the very middle of the element.
-        let center = body_size.to_point() / 2.0;
-        body.push_frame(center, first_line);
-
-        if cross {
-            // Draw the second line.
-            let second_line =
-                draw_cancel_line(ctx, length, stroke, true, &angle, body_size, span)?;
-
-            body.push_frame(center, second_line);
-        }
-
-        ctx.push(
-            FrameFragment::new(ctx, body)
-                .with_class(body_class)
-                .with_italics_correction(body_italics)
-                .with_accent_attach(body_attach)
-                .with_text_like(body_text_like),
-        );
+        let angle = match self.angle(styles) {
+            // Non specified angle defaults to the diagonal
+            Smart::Auto => None,
+            // This specifies the absolute angle w.r.t y-axis clockwise.
+            Smart::Custom(CancelAngle::Angle(angle)) => Some(angle),
+            // This specifies a function that takes the default angle as input.
+            Smart::Custom(CancelAngle::Func(func)) => {
+                let default = default_angle(Size::new(body.width(), body.height()));
+                Some(func.call(ctx.engine, [default])?.cast().at(span)?)
+            }
+        };
+
+        let fragment =
+            ctx.layout_cancel(body, angle, length, stroke, invert, cross, span);
+        ctx.push(fragment);
 
         Ok(())
     }
 }
 
-/// Defines the cancel line.
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum CancelAngle {
     Angle(Angle),
@@ -184,38 +156,13 @@ cast! {
 }
 
 /// Draws a cancel line.
-fn draw_cancel_line(
-    ctx: &mut MathContext,
-    length_scale: Rel<Abs>,
+pub(super) fn cancel_line(
+    length: Abs,
     stroke: FixedStroke,
-    invert: bool,
-    angle: &Smart<CancelAngle>,
+    angle: Angle,
     body_size: Size,
     span: Span,
-) -> SourceResult<Frame> {
-    let default = default_angle(body_size);
-    let mut angle = match angle {
-        // Non specified angle defaults to the diagonal
-        Smart::Auto => default,
-        Smart::Custom(angle) => match angle {
-            // This specifies the absolute angle w.r.t y-axis clockwise.
-            CancelAngle::Angle(v) => *v,
-            // This specifies a function that takes the default angle as input.
-            CancelAngle::Func(func) => {
-                func.call(ctx.engine, [default])?.cast().at(span)?
-            }
-        },
-    };
-
-    // invert means flipping along the y-axis
-    if invert {
-        angle *= -1.0;
-    }
-
-    // same as above, the default length is the diagonal of the body box.
-    let default_length = body_size.to_point().hypot();
-    let length = length_scale.relative_to(default_length);
-
+) -> Frame {
     // Draw a vertical line of length and rotate it by angle
     let start = Point::new(Abs::zero(), length / 2.0);
     let delta = Point::new(Abs::zero(), -length);
@@ -225,11 +172,11 @@ fn draw_cancel_line(
 
     // Having the middle of the line at the origin is convenient here.
     frame.transform(Transform::rotate(angle));
-    Ok(frame)
+    frame
 }
 
 /// The default line angle for a body of the given size.
-fn default_angle(body: Size) -> Angle {
+pub(super) fn default_angle(body: Size) -> Angle {
     // The default cancel line is the diagonal.
     // We infer the default angle from
     // the diagonal w.r.t to the body box.
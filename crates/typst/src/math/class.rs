@@ -9,6 +9,10 @@ use crate::math::{LayoutMath, MathContext};
 /// This is useful to treat certain symbols as if they were of a different
 /// class, e.g. to make a symbol behave like a relation.
 ///
+/// Content of the `{"large"}` class is treated like a big operator: It is
+/// centered on the axis and grows in display style. This also works for
+/// content that isn't a glyph of the math font, like a box or an image.
+///
 /// # Example
 /// ```example
 /// #let loves = math.class(
@@ -32,6 +36,12 @@ pub struct ClassElem {
 impl LayoutMath for ClassElem {
     #[typst_macros::time(name = "math.class", span = self.span())]
     fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
+        if *self.class() == MathClass::Large {
+            let fragment = ctx.layout_large_op(self.body())?;
+            ctx.push(fragment);
+            return Ok(());
+        }
+
         ctx.style(ctx.style.with_class(*self.class()));
         let mut fragment = ctx.layout_fragment(self.body())?;
         ctx.unstyle();
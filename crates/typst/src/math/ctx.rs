@@ -2,7 +2,7 @@ use std::f64::consts::SQRT_2;
 
 use comemo::Prehashed;
 use ecow::EcoString;
-use rustybuzz::Feature;
+use rustybuzz::{Feature, Tag};
 use ttf_parser::gsub::{AlternateSubstitution, SingleSubstitution, SubstitutionSubtable};
 use ttf_parser::math::MathValue;
 use ttf_parser::opentype_layout::LayoutTable;
@@ -13,10 +13,15 @@ use unicode_segmentation::UnicodeSegmentation;
 use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::foundations::{Content, NativeElement, Smart, StyleChain, Styles};
-use crate::layout::{Abs, Axes, BoxElem, Em, Frame, Layout, Regions, Size};
+use crate::layout::{
+    Abs, Angle, Axes, BoxElem, Corners, Em, FixedAlign, Frame, Layout, Point, Ratio,
+    Regions, Rel, Sides, Size, Transform,
+};
 use crate::math::{
-    FrameFragment, GlyphFragment, LayoutMath, MathFragment, MathRow, MathSize, MathStyle,
-    MathVariant, THICK,
+    cancel_line, default_angle, layout_accent, layout_attachments, layout_binom,
+    layout_cases, layout_cfrac, layout_evaluated, layout_underoverset, layout_xarrow,
+    Accent, Case, Delimiter, EquationElem, FrameFragment, GlyphFragment, LayoutMath,
+    MathFragment, MathRow, MathSize, MathStyle, MathVariant, DELIM_SHORT_FALL, THICK,
 };
 use crate::model::ParElem;
 use crate::realize::realize;
@@ -25,10 +30,11 @@ use crate::text::{
     features, variant, BottomEdge, BottomEdgeMetric, Font, FontStyle, FontWeight,
     TextElem, TextSize, TopEdge, TopEdgeMetric,
 };
+use crate::visualize::{DashPattern, FixedStroke, Stroke};
 
 macro_rules! scaled {
     ($ctx:expr, text: $text:ident, display: $display:ident $(,)?) => {
-        match $ctx.style.size {
+        match $ctx.current_size() {
             MathSize::Display => scaled!($ctx, $display),
             _ => scaled!($ctx, $text),
         }
@@ -54,13 +60,13 @@ pub struct MathContext<'a, 'b, 'v> {
     pub constants: ttf_parser::math::Constants<'a>,
     pub ssty_table: Option<ttf_parser::gsub::AlternateSubstitution<'a>>,
     pub glyphwise_tables: Option<Vec<GlyphwiseSubsts<'a>>>,
-    pub space_width: Em,
     pub fragments: Vec<MathFragment>,
     pub local: Styles,
     pub style: MathStyle,
     pub size: Abs,
     outer: StyleChain<'a>,
     style_stack: Vec<(MathStyle, Abs)>,
+    font_space_width: Option<Em>,
 }
 
 impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
@@ -98,11 +104,10 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
 
         let size = TextElem::size_in(styles);
         let ttf = font.ttf();
-        let space_width = ttf
+        let font_space_width = ttf
             .glyph_index(' ')
             .and_then(|id| ttf.glyph_hor_advance(id))
-            .map(|advance| font.to_em(advance))
-            .unwrap_or(THICK);
+            .map(|advance| font.to_em(advance));
 
         let variant = variant(styles);
         Self {
@@ -114,7 +119,6 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
             constants,
             ssty_table,
             glyphwise_tables,
-            space_width,
             fragments: vec![],
             local: Styles::new(),
             style: MathStyle {
@@ -124,6 +128,9 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
                 cramped: false,
                 bold: variant.weight >= FontWeight::BOLD,
                 italic: match variant.style {
+                    FontStyle::Normal if !EquationElem::auto_italic_in(styles) => {
+                        Smart::Custom(false)
+                    }
                     FontStyle::Normal => Smart::Auto,
                     FontStyle::Italic | FontStyle::Oblique => Smart::Custom(true),
                 },
@@ -131,42 +138,449 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
             size,
             outer: styles,
             style_stack: vec![],
+            font_space_width,
         }
     }
 
+    /// The width of a space in math, which is the advance of the math font's
+    /// space glyph. If the font has no such glyph, it falls back to a thick
+    /// space instead.
+    pub fn space_width(&self) -> Em {
+        self.space_width_from_font().unwrap_or(THICK)
+    }
+
+    /// The advance of the math font's space glyph, or `None` if the font has
+    /// no such glyph and `space_width` falls back to a thick space.
+    pub fn space_width_from_font(&self) -> Option<Em> {
+        self.font_space_width
+    }
+
+    /// How much shorter than the content they wrap stretched delimiters may
+    /// be.
+    pub fn delimiter_short_fall(&self) -> Abs {
+        DELIM_SHORT_FALL.scaled(self)
+    }
+
+    /// The font's minimum height of a delimited sub-formula, below which
+    /// TeX-like layout keeps delimiters at their natural size.
+    pub fn delimited_sub_formula_min_height(&self) -> Abs {
+        scaled!(self, delimited_sub_formula_min_height)
+    }
+
+    /// The font's minimum gap between the bottom of a superscript and the top
+    /// of a subscript attached to the same side of a base. Scripts closer than
+    /// this are shifted apart.
+    pub fn sub_superscript_gap_min(&self) -> Abs {
+        scaled!(self, sub_superscript_gap_min)
+    }
+
+    /// The number of design units per em of the math font, which relates the
+    /// raw values of its tables to ems.
+    pub fn units_per_em(&self) -> f64 {
+        self.font.units_per_em()
+    }
+
+    /// Converts `em` to an absolute length at the current font size, the same
+    /// way all font metrics are scaled during math layout.
+    pub fn to_abs(&self, em: Em) -> Abs {
+        em.at(self.size)
+    }
+
+    /// The stroke for a rule of the given thickness, such as a fraction bar,
+    /// painted in the current text fill. With a dash pattern, dots are as long
+    /// as the rule is thick.
+    pub fn rule_stroke(
+        &self,
+        thickness: Abs,
+        dash: Option<DashPattern<Abs>>,
+    ) -> FixedStroke {
+        let stroke = Stroke { dash: Smart::Custom(dash), ..Stroke::default() };
+        stroke.unwrap_or(FixedStroke {
+            paint: TextElem::fill_in(self.styles()).as_decoration(),
+            thickness,
+            ..FixedStroke::default()
+        })
+    }
+
     pub fn push(&mut self, fragment: impl Into<MathFragment>) {
         self.fragments.push(fragment.into());
     }
 
+    /// The resolved class of the most recently pushed fragment that has one.
+    /// Spaces and spacing are skipped.
+    pub fn last_class(&self) -> Option<MathClass> {
+        self.fragments.iter().rev().find_map(MathFragment::class)
+    }
+
     pub fn extend(&mut self, fragments: Vec<MathFragment>) {
         self.fragments.extend(fragments);
     }
 
+    /// Drains the fragments pushed so far into a row, leaving the context
+    /// empty so that layout can continue with a fresh buffer.
+    pub fn take_row(&mut self) -> MathRow {
+        MathRow::new(std::mem::take(&mut self.fragments))
+    }
+
     pub fn layout_root(&mut self, elem: &dyn LayoutMath) -> SourceResult<MathRow> {
-        let row = self.layout_fragments(elem)?;
-        Ok(MathRow::new(row))
+        self.layout_row(elem)
     }
 
     pub fn layout_fragment(
         &mut self,
         elem: &dyn LayoutMath,
     ) -> SourceResult<MathFragment> {
-        let row = self.layout_fragments(elem)?;
-        Ok(MathRow::new(row).into_fragment(self))
+        Ok(self.layout_row(elem)?.into_fragment(self))
     }
 
+    /// Lays out `elem` into fresh fragments. The fragments pushed so far are
+    /// restored afterwards, even if the layout fails.
     pub fn layout_fragments(
         &mut self,
         elem: &dyn LayoutMath,
     ) -> SourceResult<Vec<MathFragment>> {
         let prev = std::mem::take(&mut self.fragments);
-        elem.layout_math(self)?;
-        Ok(std::mem::replace(&mut self.fragments, prev))
+        let result = elem.layout_math(self);
+        let fragments = std::mem::replace(&mut self.fragments, prev);
+        result.map(|()| fragments)
     }
 
     pub fn layout_row(&mut self, elem: &dyn LayoutMath) -> SourceResult<MathRow> {
-        let fragments = self.layout_fragments(elem)?;
-        Ok(MathRow::new(fragments))
+        let prev = std::mem::take(&mut self.fragments);
+        let result = elem.layout_math(self);
+        let row = self.take_row();
+        self.fragments = prev;
+        result.map(|()| row)
+    }
+
+    /// Lays out `top` and `bottom` as limits centered over and under `base`
+    /// if its limits are active in the current style and as scripts
+    /// otherwise. Either of them may be missing. Multi-line limits are
+    /// stacked as a whole.
+    pub fn layout_limits(
+        &mut self,
+        base: MathFragment,
+        top: Option<MathFragment>,
+        bottom: Option<MathFragment>,
+    ) -> SourceResult<()> {
+        if base.limits().active(self) {
+            layout_attachments(self, base, [None, top, None, None, bottom, None])
+        } else {
+            layout_attachments(self, base, [None, None, top, None, None, bottom])
+        }
+    }
+
+    /// Lays out `base` with scripts on both of its sides, like the indices of
+    /// a tensor. Each side is given as a superscript and a subscript, either
+    /// of which may be missing.
+    ///
+    /// The left scripts mirror the right ones: they are raised and lowered by
+    /// the same shifts and kept apart by the same minimum gap, but are aligned
+    /// to the right, against the base.
+    pub fn layout_scripts(
+        &mut self,
+        base: MathFragment,
+        [pre_sup, pre_sub]: [Option<MathFragment>; 2],
+        [post_sup, post_sub]: [Option<MathFragment>; 2],
+    ) -> SourceResult<()> {
+        layout_attachments(self, base, [pre_sup, None, post_sup, pre_sub, None, post_sub])
+    }
+
+    /// Lays out the branches of a case distinction with a delimiter that is
+    /// stretched to their height, in front of them or, if `reverse` is set,
+    /// behind them. Branches with conditions are set in two left-aligned
+    /// columns, leaving the condition empty for the others.
+    pub fn layout_cases(
+        &mut self,
+        cases: &[Case],
+        delim: Delimiter,
+        reverse: bool,
+        row_gap: Rel<Abs>,
+        span: Span,
+    ) -> SourceResult<()> {
+        layout_cases(self, cases, delim, reverse, row_gap, span)
+    }
+
+    /// Arranges laid out cells in a grid with the given gaps between rows and
+    /// columns.
+    ///
+    /// Each cell is aligned horizontally within its column and on the
+    /// baseline of its row. Rows with fewer cells than the longest row are
+    /// padded with empty cells at the end. The grid is centered on the axis.
+    pub fn layout_matrix(
+        &self,
+        rows: Vec<Vec<Frame>>,
+        align: FixedAlign,
+        gap: Axes<Abs>,
+    ) -> Frame {
+        let ncols = rows.iter().map(Vec::len).max().unwrap_or(0);
+        if ncols == 0 {
+            return Frame::soft(Size::zero());
+        }
+
+        let mut widths = vec![Abs::zero(); ncols];
+        let mut heights = Vec::with_capacity(rows.len());
+        for row in &rows {
+            let (mut ascent, mut descent) = (Abs::zero(), Abs::zero());
+            for (cell, width) in row.iter().zip(&mut widths) {
+                width.set_max(cell.width());
+                ascent.set_max(cell.ascent());
+                descent.set_max(cell.descent());
+            }
+            heights.push((ascent, descent));
+        }
+
+        let width = widths.iter().sum::<Abs>() + gap.x * (ncols - 1) as f64;
+        let height = heights.iter().map(|&(a, d)| a + d).sum::<Abs>()
+            + gap.y * (rows.len() - 1) as f64;
+
+        let mut frame = Frame::soft(Size::new(width, height));
+        let mut y = Abs::zero();
+        for (row, (ascent, descent)) in rows.into_iter().zip(heights) {
+            let mut x = Abs::zero();
+            for (cell, &col_width) in row.into_iter().zip(&widths) {
+                let dx = align.position(col_width - cell.width());
+                let pos = Point::new(x + dx, y + ascent - cell.ascent());
+                frame.push_frame(pos, cell);
+                x += col_width + gap.x;
+            }
+            y += ascent + descent + gap.y;
+        }
+
+        frame.set_baseline(height / 2.0 + scaled!(self, axis_height));
+        frame
+    }
+
+    /// Overlays a line across `body`, turned by `angle` clockwise from the
+    /// vertical axis. Without an angle, the line follows the body's diagonal
+    /// from the bottom left to the top right. The length of the line is
+    /// relative to that diagonal.
+    ///
+    /// Inverting mirrors the line onto the other diagonal and crossing draws
+    /// both lines. The result keeps the class and attachments of the body.
+    #[allow(clippy::too_many_arguments)]
+    pub fn layout_cancel(
+        &self,
+        body: MathFragment,
+        angle: Option<Angle>,
+        length: Rel<Abs>,
+        stroke: FixedStroke,
+        invert: bool,
+        cross: bool,
+        span: Span,
+    ) -> FrameFragment {
+        // Preserve properties of body.
+        let class = body.class().unwrap_or(MathClass::Special);
+        let italics = body.italics_correction();
+        let attach = body.accent_attach();
+        let text_like = body.is_text_like();
+
+        let mut body = body.into_frame();
+        let size = body.size();
+        let angle = angle.unwrap_or_else(|| default_angle(size));
+        let length = length.relative_to(size.to_point().hypot());
+
+        // The origin of the lines is the very middle of the body.
+        let center = size.to_point() / 2.0;
+        let first = if invert && !cross { -angle } else { angle };
+        body.push_frame(center, cancel_line(length, stroke.clone(), first, size, span));
+        if cross {
+            body.push_frame(center, cancel_line(length, stroke, -angle, size, span));
+        }
+
+        FrameFragment::new(self, body)
+            .with_class(class)
+            .with_italics_correction(italics)
+            .with_accent_attach(attach)
+            .with_text_like(text_like)
+    }
+
+    /// Places `accent` over `base`, stretching wide accents to `width`. The
+    /// accent is centered on the base glyph's top accent attachment point
+    /// from the MATH table, or on the base's horizontal center if the glyph
+    /// has none.
+    pub fn layout_accent(
+        &self,
+        base: MathFragment,
+        accent: Accent,
+        width: Abs,
+        span: Span,
+    ) -> FrameFragment {
+        layout_accent(self, base, accent, width, span)
+    }
+
+    /// Lays out a binomial of `upper` over `lower` and pushes it, including
+    /// its stretched parentheses.
+    ///
+    /// The parts are stacked with the font's fraction gaps, but no rule is
+    /// drawn between them. Multiple lower parts are separated by commas, as in
+    /// `binom(n, k_1, k_2)`.
+    pub fn layout_binom(
+        &mut self,
+        upper: &Content,
+        lower: &[Content],
+        span: Span,
+    ) -> SourceResult<()> {
+        layout_binom(self, upper, lower, span)
+    }
+
+    /// Lays out one level of a continued fraction. Its numerator and
+    /// denominator keep the current size, so continued fractions nested in
+    /// the denominator are laid out at the same size as the outer ones, with
+    /// equally thick bars on the same axis height.
+    pub fn layout_cfrac(
+        &mut self,
+        num: &Content,
+        denom: &Content,
+        span: Span,
+    ) -> SourceResult<()> {
+        layout_cfrac(self, num, denom, span)
+    }
+
+    /// Lays out the relation symbol `c`, like an arrow or an equals sign, with
+    /// optional labels above and below. The symbol is stretched with the
+    /// font's horizontal variants or assembly if a label or `min_width` is
+    /// wider than it, and the result is spaced like a relation, as for
+    /// `x |-> f(x)`.
+    pub fn layout_xarrow(
+        &mut self,
+        c: char,
+        over: Option<&Content>,
+        under: Option<&Content>,
+        min_width: Abs,
+        span: Span,
+    ) -> SourceResult<()> {
+        layout_xarrow(self, c, over, under, min_width, span)
+    }
+
+    /// Lays out `body` followed by an evaluation bar with limits. The bar is
+    /// stretched to the body like a closing delimiter and `bottom` and `top`
+    /// are attached to its corners as scripts.
+    pub fn layout_evaluated(
+        &mut self,
+        body: &Content,
+        bottom: Option<&Content>,
+        top: Option<&Content>,
+        span: Span,
+    ) -> SourceResult<()> {
+        layout_evaluated(self, body, bottom, top, span)
+    }
+
+    /// Lays out `top` in script size, centered over `base`, like LaTeX's
+    /// `\overset`. The result keeps the class of the base.
+    pub fn layout_overset(&mut self, top: &Content, base: &Content) -> SourceResult<()> {
+        layout_underoverset(self, top, base, true)
+    }
+
+    /// Lays out `bottom` in script size, centered under `base`, like LaTeX's
+    /// `\underset`. The result keeps the class of the base.
+    pub fn layout_underset(
+        &mut self,
+        bottom: &Content,
+        base: &Content,
+    ) -> SourceResult<()> {
+        layout_underoverset(self, bottom, base, false)
+    }
+
+    /// Lays out `elem` and returns an empty fragment that reserves the space
+    /// it would take, like TeX's `\phantom`. Only the selected dimensions are
+    /// kept, the others collapse to zero, giving `\hphantom` and `\vphantom`.
+    pub fn layout_phantom(
+        &mut self,
+        elem: &dyn LayoutMath,
+        horizontal: bool,
+        vertical: bool,
+    ) -> SourceResult<FrameFragment> {
+        let fragment = self.layout_fragment(elem)?;
+        let width = if horizontal { fragment.width() } else { Abs::zero() };
+        let (ascent, descent) = if vertical {
+            (fragment.ascent(), fragment.descent())
+        } else {
+            (Abs::zero(), Abs::zero())
+        };
+
+        let mut frame = Frame::soft(Size::new(width, ascent + descent));
+        frame.set_baseline(ascent);
+        Ok(FrameFragment::new(self, frame)
+            .with_class(fragment.class().unwrap_or(MathClass::Normal)))
+    }
+
+    /// Lays out `elem` and draws it without taking up vertical space, like
+    /// TeX's `\smash`.
+    pub fn layout_smash(&mut self, elem: &dyn LayoutMath) -> SourceResult<FrameFragment> {
+        let fragment = self.layout_fragment(elem)?;
+        let class = fragment.class().unwrap_or(MathClass::Normal);
+        let inner = fragment.into_frame();
+
+        let mut frame = Frame::soft(Size::with_x(inner.width()));
+        frame.set_baseline(Abs::zero());
+        frame.push_frame(Point::with_y(-inner.baseline()), inner);
+        Ok(FrameFragment::new(self, frame).with_class(class))
+    }
+
+    /// Lays out `elem` and draws a rectangle around it, like LaTeX's
+    /// `\boxed`. The rectangle is `inset` away from the body on each side.
+    ///
+    /// The body stays on the baseline and the result is spaced like an
+    /// ordinary atom, whatever the class of the body.
+    pub fn layout_boxed(
+        &mut self,
+        elem: &dyn LayoutMath,
+        inset: Sides<Abs>,
+        stroke: Option<FixedStroke>,
+        span: Span,
+    ) -> SourceResult<FrameFragment> {
+        let inner = self.layout_fragment(elem)?.into_frame();
+        let size = inner.size() + inset.sum_by_axis();
+        let baseline = inset.top + inner.baseline();
+
+        let mut frame = Frame::soft(size);
+        frame.set_baseline(baseline);
+        frame.push_frame(Point::new(inset.left, inset.top), inner);
+        frame.fill_and_stroke(
+            None,
+            Sides::splat(stroke),
+            Sides::splat(Rel::zero()),
+            Corners::splat(Rel::zero()),
+            span,
+        );
+
+        Ok(FrameFragment::new(self, frame))
+    }
+
+    /// Lays out `elem` as a large operator, like a big integral or sum.
+    ///
+    /// Single glyphs are handled by `layout_text`, which picks a larger variant
+    /// from the font in display style. Any other content is scaled up to the
+    /// same minimum height instead. Either way, the operator is centered on the
+    /// axis.
+    pub fn layout_large_op(
+        &mut self,
+        elem: &dyn LayoutMath,
+    ) -> SourceResult<MathFragment> {
+        self.style(self.style.with_class(MathClass::Large));
+        let result = self.layout_fragment(elem);
+        self.unstyle();
+        let mut fragment = result?;
+
+        if matches!(fragment, MathFragment::Glyph(_) | MathFragment::Variant(_)) {
+            fragment.set_class(MathClass::Large);
+            return Ok(fragment);
+        }
+
+        let mut frame = fragment.into_frame();
+        let height = frame.height();
+        if self.style.size == MathSize::Display && !height.is_zero() {
+            let target = scaled!(self, display_operator_min_height).max(SQRT_2 * height);
+            let factor = Ratio::new(target / height);
+            frame.transform(Transform::scale(factor, factor));
+            frame.set_size(frame.size() * factor.get());
+        }
+
+        let axis = scaled!(self, axis_height);
+        let offset = EquationElem::op_offset_in(self.styles());
+        frame.set_baseline(frame.height() / 2.0 + axis + offset);
+        Ok(FrameFragment::new(self, frame).with_class(MathClass::Large).into())
     }
 
     pub fn layout_frame(&mut self, elem: &dyn LayoutMath) -> SourceResult<Frame> {
@@ -180,8 +594,19 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
     }
 
     pub fn layout_content(&mut self, content: &Content) -> SourceResult<Frame> {
+        self.layout_content_in(content, self.regions)
+    }
+
+    /// Lays out content in the given regions instead of the context's own,
+    /// which never expand. Useful for content that should fill or be
+    /// constrained by a specific size.
+    pub fn layout_content_in(
+        &mut self,
+        content: &Content,
+        regions: Regions,
+    ) -> SourceResult<Frame> {
         Ok(content
-            .layout(self.engine, self.outer.chain(&self.local), self.regions)?
+            .layout(self.engine, self.outer.chain(&self.local), regions)?
             .into_frame())
     }
 
@@ -217,6 +642,8 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
                 };
                 // TeXbook p 155. Large operators are always vertically centered on the axis.
                 variant.center_on_axis(self);
+                let offset = EquationElem::op_offset_in(self.styles());
+                variant.frame.set_baseline(variant.frame.baseline() + offset);
                 variant.into()
             } else {
                 glyph.into()
@@ -258,6 +685,11 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
         Ok(fragment)
     }
 
+    /// Lays out text that isn't a single glyph with Typst's text layout.
+    ///
+    /// The text size comes from the local styles, which `style` updates for
+    /// every change of the math size, so text in scripts shrinks just like
+    /// single glyphs do.
     pub fn layout_complex_text(
         &mut self,
         text: &str,
@@ -293,8 +725,90 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
             .with_spaced(spaced))
     }
 
+    /// Lay out with an additional glyph-wise OpenType feature of the math
+    /// font, like a stylistic set, enabled for the duration of `f`. The
+    /// previous features are restored afterwards. Has no effect if the font
+    /// does not provide the feature.
+    pub fn with_feature<T>(
+        &mut self,
+        tag: [u8; 4],
+        f: impl FnOnce(&mut Self) -> SourceResult<T>,
+    ) -> SourceResult<T> {
+        let feature = Feature::new(Tag::from_bytes(&tag), 1, ..);
+        let Some(subst) = self
+            .ttf
+            .tables()
+            .gsub
+            .and_then(|gsub| GlyphwiseSubsts::new(gsub, feature))
+        else {
+            return f(self);
+        };
+
+        let had_tables = self.glyphwise_tables.is_some();
+        let tables = self.glyphwise_tables.get_or_insert_with(Vec::new);
+        let len = tables.len();
+        tables.push(subst);
+        let result = f(self);
+        if !had_tables {
+            self.glyphwise_tables = None;
+        } else if let Some(tables) = &mut self.glyphwise_tables {
+            tables.truncate(len);
+        }
+        result
+    }
+
+    /// Lay out without any glyph-wise OpenType substitutions of the math font
+    /// for the duration of `f`, so that glyphs come out as they are in the
+    /// font. This also disables the script-style alternates of the `ssty`
+    /// feature. Both are restored afterwards.
+    ///
+    /// Text that is shaped as a whole, like multi-letter words, is not
+    /// affected.
+    pub fn without_substitutions<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> SourceResult<T>,
+    ) -> SourceResult<T> {
+        let tables = self.glyphwise_tables.take();
+        let ssty = self.ssty_table.take();
+        let result = f(self);
+        self.glyphwise_tables = tables;
+        self.ssty_table = ssty;
+        result
+    }
+
+    /// The math style that is currently active.
+    pub fn current_style(&self) -> MathStyle {
+        self.style
+    }
+
+    /// The size at which math is currently laid out, e.g. `Display` in block
+    /// equations or `Script` in attachments.
+    pub fn current_size(&self) -> MathSize {
+        self.style.size
+    }
+
+    /// Whether the current style is cramped, which lowers superscripts.
+    ///
+    /// As in TeX, subscripts, denominators, radicands, and the bases of
+    /// accents and overlines are cramped, and so are the cells of vectors and
+    /// matrices. Everything else inherits the state of its surroundings, so
+    /// the base of a superscript is only cramped if the whole attachment is.
+    pub fn is_cramped(&self) -> bool {
+        self.style.cramped
+    }
+
+    /// The styles the equation is laid out with, without the local styles
+    /// that math style changes like `bold` or script sizes push. Chaining
+    /// other styles onto this gives a view that is independent of the current
+    /// math style.
+    pub fn outer_styles(&self) -> StyleChain<'a> {
+        self.outer
+    }
+
+    /// The current styles, that is, the local math styles chained onto the
+    /// outer ones.
     pub fn styles(&self) -> StyleChain {
-        self.outer.chain(&self.local)
+        self.outer_styles().chain(&self.local)
     }
 
     pub fn realize(&mut self, content: &Content) -> SourceResult<Option<Content>> {
@@ -312,6 +826,11 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
             } else {
                 FontStyle::Normal
             }));
+        // Upright text looks the same whether single letters are italicized
+        // automatically or not, so keep track of that separately. Otherwise,
+        // equations nested in upright math would start italicizing again.
+        self.local
+            .set(EquationElem::set_auto_italic(style.italic != Smart::Custom(false)));
         self.local.set(TextElem::set_weight(if style.bold {
             FontWeight::BOLD
         } else {
@@ -327,6 +846,7 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
         self.local.unset();
         self.local.unset();
         self.local.unset();
+        self.local.unset();
     }
 }
 
@@ -336,19 +856,19 @@ pub(super) trait Scaled {
 
 impl Scaled for i16 {
     fn scaled(self, ctx: &MathContext) -> Abs {
-        ctx.font.to_em(self).scaled(ctx)
+        Em::from_units(self, ctx.units_per_em()).scaled(ctx)
     }
 }
 
 impl Scaled for u16 {
     fn scaled(self, ctx: &MathContext) -> Abs {
-        ctx.font.to_em(self).scaled(ctx)
+        Em::from_units(self, ctx.units_per_em()).scaled(ctx)
     }
 }
 
 impl Scaled for Em {
     fn scaled(self, ctx: &MathContext) -> Abs {
-        self.at(ctx.size)
+        ctx.to_abs(self)
     }
 }
 
@@ -8,8 +8,8 @@ use crate::foundations::{
 };
 use crate::introspection::{Count, Counter, CounterUpdate, Locatable};
 use crate::layout::{
-    Abs, Align, AlignElem, Axes, Dir, Em, FixedAlign, Fragment, Frame, Layout, Point,
-    Regions, Size,
+    Abs, Align, AlignElem, Axes, Em, FixedAlign, Fragment, Frame, HAlign, Layout, Length,
+    Point, Regions, Size,
 };
 use crate::math::{LayoutMath, MathContext};
 use crate::model::{Numbering, Outlinable, ParElem, Refable, Supplement};
@@ -66,6 +66,26 @@ pub struct EquationElem {
     /// ```
     pub numbering: Option<Numbering>,
 
+    /// A custom tag for a block-level equation.
+    ///
+    /// If set, the tag is displayed in place of the equation's number and
+    /// references to the equation show the tag instead of a number. Tagged
+    /// equations don't step the equation counter.
+    ///
+    /// ```example
+    /// #set math.equation(numbering: "(1)")
+    ///
+    /// $ a + b = c $ <first>
+    /// #math.equation(
+    ///   block: true,
+    ///   tag: [($star$)],
+    ///   $ c = a + b $,
+    /// ) <star>
+    ///
+    /// From @first follows @star.
+    /// ```
+    pub tag: Option<Content>,
+
     /// A supplement for the equation.
     ///
     /// For references to equations, this is added before the referenced number.
@@ -84,6 +104,77 @@ pub struct EquationElem {
     /// ```
     pub supplement: Smart<Option<Supplement>>,
 
+    /// On which side of a block-level equation its number is placed.
+    ///
+    /// The number can be placed at the `{start}` or `{end}` of the line or on
+    /// a fixed side with `{left}` or `{right}`. References to the equation are
+    /// not affected by this.
+    ///
+    /// ```example
+    /// #set math.equation(numbering: "(1)", number-align: left)
+    ///
+    /// $ a^2 + b^2 = c^2 $ <pythagoras>
+    ///
+    /// By @pythagoras, ...
+    /// ```
+    #[resolve]
+    #[default(HAlign::End)]
+    pub number_align: HAlign,
+
+    /// How far large operators like sums and integrals are raised above the
+    /// math axis they are centered on. Negative values lower them.
+    ///
+    /// ```example
+    /// $ sum_(i=0)^n i $
+    /// #set math.equation(op-offset: 0.2em)
+    /// $ sum_(i=0)^n i $
+    /// ```
+    #[resolve]
+    pub op_offset: Length,
+
+    /// Whether single letters are italicized automatically.
+    ///
+    /// By default, single letters are set in italics while longer text stays
+    /// upright. For notations like chemical formulas or units, you can disable
+    /// this to set single letters upright, too. The [`italic`]($math.italic)
+    /// function still italicizes its body.
+    ///
+    /// ```example
+    /// $ x + 2 y $
+    /// #set math.equation(auto-italic: false)
+    /// $ "H"_2 O + C O_2 $
+    /// ```
+    #[default(true)]
+    pub auto_italic: bool,
+
+    /// Whether a thin space is inserted automatically before differentials.
+    ///
+    /// If this is enabled, a `d` followed by a single letter, like in `d x`,
+    /// is spaced like [`dif`]($math.dif) when something other than an opening
+    /// delimiter precedes it. The letters keep their style.
+    ///
+    /// ```example
+    /// $ integral f(x) d x $
+    /// #set math.equation(auto-dif: true)
+    /// $ integral f(x) d x $
+    /// ```
+    #[default(false)]
+    pub auto_dif: bool,
+
+    /// Whether delimiters keep their natural size around low sub-formulas.
+    ///
+    /// If this is enabled, matched delimiters only stretch around content
+    /// that is at least as high as the math font's minimum height for
+    /// delimited sub-formulas, like in TeX.
+    ///
+    /// ```example
+    /// $ (x^2) [a_n] $
+    /// #set math.equation(optical-delims: true)
+    /// $ (x^2) [a_n] $
+    /// ```
+    #[default(false)]
+    pub optical_delims: bool,
+
     /// The contents of the equation.
     #[required]
     pub body: Content,
@@ -100,12 +191,13 @@ impl Synthesize for EquationElem {
             Smart::Auto => TextElem::packed(Self::local_name_in(styles)),
             Smart::Custom(None) => Content::empty(),
             Smart::Custom(Some(supplement)) => {
-                supplement.resolve(engine, [self.clone()])?
+                supplement.resolve(engine, self.span(), [self.clone()])?
             }
         };
 
         self.push_block(self.block(styles));
         self.push_numbering(self.numbering(styles));
+        self.push_tag(self.tag(styles));
         self.push_supplement(Smart::Custom(Some(Supplement::Content(supplement))));
 
         Ok(())
@@ -215,12 +307,25 @@ impl Layout for EquationElem {
         let mut ctx = MathContext::new(engine, styles, regions, &font, true);
         let mut frame = ctx.layout_frame(self)?;
 
-        if let Some(numbering) = self.numbering(styles) {
+        let number = match self.tag(styles) {
+            Some(tag) => Some(tag),
+            None => self.numbering(styles).map(|numbering| {
+                Counter::of(Self::elem()).display(self.span(), Some(numbering), false)
+            }),
+        };
+
+        if let Some(number) = number {
+            let number_align = self.number_align(styles);
+            if number_align == FixedAlign::Center {
+                bail!(
+                    self.span(),
+                    "equation numbers cannot be centered";
+                    hint: "use `start`, `end`, `left`, or `right` instead",
+                );
+            }
+
             let pod = Regions::one(regions.base(), Axes::splat(false));
-            let counter = Counter::of(Self::elem())
-                .display(self.span(), Some(numbering), false)
-                .layout(engine, styles, pod)?
-                .into_frame();
+            let counter = number.layout(engine, styles, pod)?.into_frame();
 
             let full_counter_width = counter.width() + NUMBER_GUTTER.resolve(styles);
             let width = if regions.size.x.is_finite() {
@@ -233,18 +338,18 @@ impl Layout for EquationElem {
             let align = AlignElem::alignment_in(styles).resolve(styles).x;
             frame.resize(Size::new(width, height), Axes::splat(align));
 
-            let dir = TextElem::dir_in(styles);
-            let offset = match (align, dir) {
-                (FixedAlign::Start, Dir::RTL) => full_counter_width,
-                (FixedAlign::End, Dir::LTR) => -full_counter_width,
+            // Move the equation out of the number's way if both are on the
+            // same side.
+            let offset = match (align, number_align) {
+                (FixedAlign::Start, FixedAlign::Start) => full_counter_width,
+                (FixedAlign::End, FixedAlign::End) => -full_counter_width,
                 _ => Abs::zero(),
             };
             frame.translate(Point::with_x(offset));
 
-            let x = if dir.is_positive() {
-                frame.width() - counter.width()
-            } else {
-                Abs::zero()
+            let x = match number_align {
+                FixedAlign::Start => Abs::zero(),
+                _ => frame.width() - counter.width(),
             };
             let y = (frame.height() - counter.height()) / 2.0;
 
@@ -261,6 +366,7 @@ impl Layout for EquationElem {
 impl Count for EquationElem {
     fn update(&self) -> Option<CounterUpdate> {
         (self.block(StyleChain::default())
+            && self.tag(StyleChain::default()).is_none()
             && self.numbering(StyleChain::default()).is_some())
         .then(|| CounterUpdate::Step(NonZeroUsize::ONE))
     }
@@ -319,6 +425,10 @@ impl Refable for EquationElem {
     fn numbering(&self) -> Option<Numbering> {
         self.numbering(StyleChain::default())
     }
+
+    fn tag(&self) -> Option<Content> {
+        self.tag(StyleChain::default())
+    }
 }
 
 impl Outlinable for EquationElem {
@@ -326,8 +436,17 @@ impl Outlinable for EquationElem {
         if !self.block(StyleChain::default()) {
             return Ok(None);
         }
-        let Some(numbering) = self.numbering(StyleChain::default()) else {
-            return Ok(None);
+
+        let numbers = match (
+            self.tag(StyleChain::default()),
+            self.numbering(StyleChain::default()),
+        ) {
+            (Some(tag), _) => tag,
+            (None, Some(numbering)) => self
+                .counter()
+                .at(engine, self.location().unwrap())?
+                .display(engine, &numbering)?,
+            (None, None) => return Ok(None),
         };
 
         // After synthesis, this should always be custom content.
@@ -340,11 +459,6 @@ impl Outlinable for EquationElem {
             supplement += TextElem::packed("\u{a0}");
         }
 
-        let numbers = self
-            .counter()
-            .at(engine, self.location().unwrap())?
-            .display(engine, &numbering)?;
-
         Ok(Some(supplement + numbers))
     }
 }
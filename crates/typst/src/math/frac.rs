@@ -1,13 +1,12 @@
 use crate::diag::{bail, SourceResult};
-use crate::foundations::{elem, Content, NativeElement, Value};
-use crate::layout::{Em, Frame, FrameItem, Point, Size};
+use crate::foundations::{elem, Content, NativeElement, Resolve, Smart, Value};
+use crate::layout::{Abs, Em, Frame, FrameItem, Length, Point, Size};
 use crate::math::{
     FrameFragment, GlyphFragment, LayoutMath, MathContext, MathSize, Scaled,
-    DELIM_SHORT_FALL,
 };
 use crate::syntax::{Span, Spanned};
 use crate::text::TextElem;
-use crate::visualize::{FixedStroke, Geometry};
+use crate::visualize::{DashPattern, Geometry, Paint};
 
 const FRAC_AROUND: Em = Em::new(0.1);
 
@@ -33,12 +32,102 @@ pub struct FracElem {
     /// The fraction's denominator.
     #[required]
     pub denom: Content,
+
+    /// The thickness of the fraction bar.
+    ///
+    /// When set to `{auto}`, the thickness is taken from the math font.
+    ///
+    /// ```example
+    /// #set math.frac(thickness: 1.5pt)
+    /// $ a/b $
+    /// ```
+    pub thickness: Smart<Length>,
+
+    /// The minimum gap between the numerator and the fraction bar.
+    ///
+    /// When set to `{auto}`, the gap is taken from the math font.
+    pub num_gap: Smart<Length>,
+
+    /// The minimum gap between the fraction bar and the denominator.
+    ///
+    /// When set to `{auto}`, the gap is taken from the math font.
+    pub denom_gap: Smart<Length>,
+
+    /// The dash pattern of the fraction bar.
+    ///
+    /// When set to `{none}`, the bar is solid.
+    ///
+    /// ```example
+    /// #set math.frac(dash: "dashed")
+    /// $ a/b $
+    /// ```
+    pub dash: Option<DashPattern>,
+
+    /// The paint of the fraction bar.
+    ///
+    /// When set to `{none}`, the bar is painted like the surrounding text. The
+    /// numerator and denominator keep their paint either way.
+    ///
+    /// ```example
+    /// #set math.frac(bar-fill: blue)
+    /// $ a/b $
+    /// ```
+    pub bar_fill: Option<Paint>,
 }
 
 impl LayoutMath for FracElem {
     #[typst_macros::time(name = "math.frac", span = self.span())]
     fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
-        layout(ctx, self.num(), std::slice::from_ref(self.denom()), false, self.span())
+        let styles = ctx.styles();
+        let resolve =
+            |length: Smart<Length>| length.map(|v| v.resolve(styles)).as_custom();
+        let overrides = Overrides {
+            thickness: resolve(self.thickness(styles)),
+            num_gap: resolve(self.num_gap(styles)),
+            denom_gap: resolve(self.denom_gap(styles)),
+            dash: self.dash(styles).map(|dash| dash.resolve(styles)),
+            bar_fill: self.bar_fill(styles),
+        };
+
+        layout(
+            ctx,
+            self.num(),
+            std::slice::from_ref(self.denom()),
+            false,
+            false,
+            overrides,
+            self.span(),
+        )
+    }
+}
+
+/// A continued fraction.
+///
+/// Unlike nested [fractions]($math.frac), the numerator and denominator of a
+/// continued fraction keep the size of the fraction itself instead of
+/// shrinking. This way, all levels of a continued fraction have the same size,
+/// no matter how deeply they are nested. The fraction bar is styled like the
+/// one of regular fractions.
+///
+/// # Example
+/// ```example
+/// $ x = cfrac(1, 1 + cfrac(1, 1 + cfrac(1, 1 + x))) $
+/// ```
+#[elem(title = "Continued Fraction", LayoutMath)]
+pub struct CfracElem {
+    /// The continued fraction's numerator.
+    #[required]
+    pub num: Content,
+
+    /// The continued fraction's denominator.
+    #[required]
+    pub denom: Content,
+}
+
+impl LayoutMath for CfracElem {
+    #[typst_macros::time(name = "math.cfrac", span = self.span())]
+    fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
+        ctx.layout_cfrac(self.num(), self.denom(), self.span())
     }
 }
 
@@ -72,21 +161,69 @@ pub struct BinomElem {
 impl LayoutMath for BinomElem {
     #[typst_macros::time(name = "math.binom", span = self.span())]
     fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
-        layout(ctx, self.upper(), self.lower(), true, self.span())
+        ctx.layout_binom(self.upper(), self.lower(), self.span())
     }
 }
 
-/// Layout a fraction or binomial.
+/// Layout a binomial: the upper and lower parts stacked with the fraction gaps
+/// but without a rule, wrapped in stretched parentheses. Multiple lower parts
+/// are separated by commas.
+pub(super) fn layout_binom(
+    ctx: &mut MathContext,
+    upper: &Content,
+    lower: &[Content],
+    span: Span,
+) -> SourceResult<()> {
+    layout(ctx, upper, lower, true, false, Overrides::default(), span)
+}
+
+/// Layout one level of a continued fraction: like a fraction, but with the
+/// numerator and denominator in the current size instead of a smaller one.
+/// The fraction bar follows the styles of regular fractions.
+pub(super) fn layout_cfrac(
+    ctx: &mut MathContext,
+    num: &Content,
+    denom: &Content,
+    span: Span,
+) -> SourceResult<()> {
+    let styles = ctx.styles();
+    let resolve = |length: Smart<Length>| length.map(|v| v.resolve(styles)).as_custom();
+    let overrides = Overrides {
+        thickness: resolve(FracElem::thickness_in(styles)),
+        num_gap: resolve(FracElem::num_gap_in(styles)),
+        denom_gap: resolve(FracElem::denom_gap_in(styles)),
+        dash: FracElem::dash_in(styles).map(|dash| dash.resolve(styles)),
+        bar_fill: FracElem::bar_fill_in(styles),
+    };
+    layout(ctx, num, std::slice::from_ref(denom), false, true, overrides, span)
+}
+
+/// Style overrides for the font's fraction metrics.
+#[derive(Debug, Default, Clone)]
+struct Overrides {
+    thickness: Option<Abs>,
+    num_gap: Option<Abs>,
+    denom_gap: Option<Abs>,
+    dash: Option<DashPattern<Abs>>,
+    bar_fill: Option<Paint>,
+}
+
+/// Layout a fraction or binomial. Continued fractions keep their parts in the
+/// current size.
 fn layout(
     ctx: &mut MathContext,
     num: &Content,
     denom: &[Content],
     binom: bool,
+    continued: bool,
+    overrides: Overrides,
     span: Span,
 ) -> SourceResult<()> {
-    let short_fall = DELIM_SHORT_FALL.scaled(ctx);
+    let short_fall = ctx.delimiter_short_fall();
     let axis = scaled!(ctx, axis_height);
-    let thickness = scaled!(ctx, fraction_rule_thickness);
+    let thickness = overrides
+        .thickness
+        .unwrap_or_else(|| scaled!(ctx, fraction_rule_thickness));
     let shift_up = scaled!(
         ctx,
         text: fraction_numerator_shift_up,
@@ -97,22 +234,32 @@ fn layout(
         text: fraction_denominator_shift_down,
         display: fraction_denominator_display_style_shift_down,
     );
-    let num_min = scaled!(
-        ctx,
-        text: fraction_numerator_gap_min,
-        display: fraction_num_display_style_gap_min,
-    );
-    let denom_min = scaled!(
-        ctx,
-        text: fraction_denominator_gap_min,
-        display: fraction_denom_display_style_gap_min,
-    );
+    let num_min = overrides.num_gap.unwrap_or_else(|| {
+        scaled!(
+            ctx,
+            text: fraction_numerator_gap_min,
+            display: fraction_num_display_style_gap_min,
+        )
+    });
+    let denom_min = overrides.denom_gap.unwrap_or_else(|| {
+        scaled!(
+            ctx,
+            text: fraction_denominator_gap_min,
+            display: fraction_denom_display_style_gap_min,
+        )
+    });
 
-    ctx.style(ctx.style.for_numerator());
+    let (num_style, denom_style) = if continued {
+        (ctx.style, ctx.style.with_cramped(true))
+    } else {
+        (ctx.style.for_numerator(), ctx.style.for_denominator())
+    };
+
+    ctx.style(num_style);
     let num = ctx.layout_frame(num)?;
     ctx.unstyle();
 
-    ctx.style(ctx.style.for_denominator());
+    ctx.style(denom_style);
     let denom = ctx.layout_frame(&Content::sequence(
         // Add a comma between each element.
         denom.iter().flat_map(|a| [TextElem::packed(','), a.clone()]).skip(1),
@@ -149,14 +296,14 @@ fn layout(
         right.center_on_axis(ctx);
         ctx.push(right);
     } else {
+        let mut stroke = ctx.rule_stroke(thickness, overrides.dash);
+        if let Some(paint) = overrides.bar_fill {
+            stroke.paint = paint;
+        }
         frame.push(
             line_pos,
             FrameItem::Shape(
-                Geometry::Line(Point::with_x(line_width)).stroked(FixedStroke {
-                    paint: TextElem::fill_in(ctx.styles()).as_decoration(),
-                    thickness,
-                    ..FixedStroke::default()
-                }),
+                Geometry::Line(Point::with_x(line_width)).stroked(stroke),
                 span,
             ),
         );
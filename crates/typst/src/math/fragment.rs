@@ -259,12 +259,12 @@ impl GlyphFragment {
             lang: TextElem::lang_in(ctx.styles()),
             fill: TextElem::fill_in(ctx.styles()).as_decoration(),
             shift: TextElem::baseline_in(ctx.styles()),
-            style: ctx.style,
+            style: ctx.current_style(),
             font_size: ctx.size,
             width: Abs::zero(),
             ascent: Abs::zero(),
             descent: Abs::zero(),
-            limits: Limits::for_char(c),
+            limits: Limits::for_char_in(c, ctx),
             italics_correction: Abs::zero(),
             accent_attach: Abs::zero(),
             class,
@@ -433,7 +433,7 @@ impl FrameFragment {
         Self {
             frame,
             font_size: ctx.size,
-            style: ctx.style,
+            style: ctx.current_style(),
             class: MathClass::Normal,
             limits: Limits::Never,
             spaced: false,
@@ -456,6 +456,15 @@ impl FrameFragment {
         Self { spaced, ..self }
     }
 
+    /// Overrides the baseline of the frame, measured from its top edge.
+    ///
+    /// The base ascent follows along; call `with_base_ascent` afterwards to
+    /// set it separately.
+    pub fn with_baseline(mut self, baseline: Abs) -> Self {
+        self.frame.set_baseline(baseline);
+        Self { base_ascent: baseline, ..self }
+    }
+
     pub fn with_base_ascent(self, base_ascent: Abs) -> Self {
         Self { base_ascent, ..self }
     }
@@ -534,3 +543,102 @@ fn kern_at_height(
 
     Some(kern.kern(i)?.scaled(ctx))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{MathRow, MathSize, MathVariant};
+
+    fn style() -> MathStyle {
+        MathStyle {
+            variant: MathVariant::Serif,
+            size: MathSize::Text,
+            class: Smart::Auto,
+            cramped: false,
+            bold: false,
+            italic: Smart::Auto,
+        }
+    }
+
+    fn frame_fragment() -> MathFragment {
+        let frame = Frame::soft(Size::new(Abs::pt(5.0), Abs::pt(10.0)));
+        MathFragment::Frame(FrameFragment {
+            base_ascent: frame.ascent(),
+            accent_attach: frame.width() / 2.0,
+            frame,
+            style: style(),
+            font_size: Abs::pt(10.0),
+            class: MathClass::Normal,
+            limits: Limits::Never,
+            spaced: false,
+            italics_correction: Abs::zero(),
+            text_like: false,
+        })
+    }
+
+    fn variant_fragment(frame: Frame) -> MathFragment {
+        MathFragment::Variant(VariantFragment {
+            c: '(',
+            id: None,
+            italics_correction: Abs::zero(),
+            accent_attach: frame.width() / 2.0,
+            frame,
+            style: style(),
+            font_size: Abs::pt(10.0),
+            class: Some(MathClass::Opening),
+            span: Span::detached(),
+            limits: Limits::Never,
+            mid_stretched: None,
+        })
+    }
+
+    #[test]
+    fn test_ascent_and_descent_add_up_to_height() {
+        let mut frame = Frame::soft(Size::new(Abs::pt(4.0), Abs::pt(12.0)));
+        frame.set_baseline(Abs::pt(9.0));
+
+        let mut fragment = frame_fragment();
+        if let MathFragment::Frame(inner) = &mut fragment {
+            inner.frame = frame.clone();
+        }
+
+        for fragment in [fragment, variant_fragment(frame)] {
+            assert_eq!(fragment.ascent(), Abs::pt(9.0));
+            assert_eq!(fragment.descent(), Abs::pt(3.0));
+            assert_eq!(fragment.ascent() + fragment.descent(), fragment.height());
+        }
+
+        // Without an explicit baseline, everything is above it.
+        let fragment = frame_fragment();
+        assert_eq!(fragment.ascent(), fragment.height());
+        assert_eq!(fragment.descent(), Abs::zero());
+    }
+
+    #[test]
+    fn test_with_baseline_overrides_frame_baseline() {
+        let MathFragment::Frame(inner) = frame_fragment() else { unreachable!() };
+        let fragment = inner.with_baseline(Abs::pt(4.0));
+        assert_eq!(fragment.frame.baseline(), Abs::pt(4.0));
+        assert_eq!(fragment.base_ascent, Abs::pt(4.0));
+
+        let fragment = MathFragment::from(fragment);
+        assert_eq!(fragment.ascent(), Abs::pt(4.0));
+        assert_eq!(fragment.descent(), Abs::pt(6.0));
+    }
+
+    #[test]
+    fn test_with_baseline_affects_row_alignment() {
+        let MathFragment::Frame(inner) = frame_fragment() else { unreachable!() };
+        let shifted = inner.clone().with_baseline(Abs::pt(4.0));
+        let row = MathRow::new(vec![frame_fragment(), shifted.into()]);
+
+        // The plain fragment sits on the baseline, while the shifted one hangs
+        // below it, so the row extends in both directions.
+        assert_eq!(row.ascent(), Abs::pt(10.0));
+        assert_eq!(row.descent(), Abs::pt(6.0));
+
+        let row = MathRow::new(vec![inner.with_baseline(Abs::pt(4.0)).into()]);
+        assert_eq!(row.ascent(), Abs::pt(4.0));
+        assert_eq!(row.descent(), Abs::pt(6.0));
+    }
+}
@@ -4,8 +4,10 @@ use crate::diag::SourceResult;
 use crate::foundations::{elem, func, Content, NativeElement, Resolve, Smart};
 use crate::layout::{Abs, Em, Length, Rel};
 use crate::math::{
-    GlyphFragment, LayoutMath, MathContext, MathFragment, Scaled, SpacingFragment,
+    EquationElem, GlyphFragment, LayoutMath, MathContext, MathFragment, Scaled,
+    SpacingFragment,
 };
+use crate::syntax::Span;
 use crate::text::TextElem;
 
 /// How much less high scaled delimiters can be than what they wrap.
@@ -134,6 +136,65 @@ impl LayoutMath for MidElem {
     }
 }
 
+/// An evaluation bar with limits, like `\left. F(x) \right|_a^b` in LaTeX.
+///
+/// The bar after the body is stretched to the body's height and the limits
+/// are attached to its bottom and top corners.
+///
+/// ```example
+/// $ integral_0^1 x dif x = evaluated(x^2/2, bottom: 0, top: 1) $
+/// ```
+#[elem(title = "Evaluation Bar", LayoutMath)]
+pub struct EvaluatedElem {
+    /// The expression that is evaluated.
+    #[required]
+    pub body: Content,
+
+    /// The lower limit, attached to the bottom of the bar.
+    pub bottom: Option<Content>,
+
+    /// The upper limit, attached to the top of the bar.
+    pub top: Option<Content>,
+}
+
+impl LayoutMath for EvaluatedElem {
+    #[typst_macros::time(name = "math.evaluated", span = self.span())]
+    fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
+        let styles = ctx.styles();
+        ctx.layout_evaluated(
+            self.body(),
+            self.bottom(styles).as_ref(),
+            self.top(styles).as_ref(),
+            self.span(),
+        )
+    }
+}
+
+/// Layout `body` followed by a bar that is stretched like a closing
+/// delimiter, with `bottom` and `top` attached to the bar as scripts.
+pub(super) fn layout_evaluated(
+    ctx: &mut MathContext,
+    body: &Content,
+    bottom: Option<&Content>,
+    top: Option<&Content>,
+    span: Span,
+) -> SourceResult<()> {
+    let delimited = LrElem::new(body.clone() + TextElem::packed('|').spanned(span));
+    let mut fragments = ctx.layout_fragments(&delimited.spanned(span).pack())?;
+    let Some(bar) = fragments.pop() else { return Ok(()) };
+    ctx.extend(fragments);
+
+    ctx.style(ctx.style.for_superscript());
+    let top = top.map(|top| ctx.layout_fragment(top)).transpose()?;
+    ctx.unstyle();
+
+    ctx.style(ctx.style.for_subscript());
+    let bottom = bottom.map(|bottom| ctx.layout_fragment(bottom)).transpose()?;
+    ctx.unstyle();
+
+    ctx.layout_scripts(bar, [None, None], [top, bottom])
+}
+
 /// Scale a math fragment to a height.
 fn scale(
     ctx: &mut MathContext,
@@ -153,7 +214,17 @@ fn scale(
             _ => return,
         };
 
-        let short_fall = DELIM_SHORT_FALL.scaled(ctx);
+        // Like in TeX, delimiters around sub-formulas that are lower than the
+        // font's minimum can keep their natural size.
+        let height = if EquationElem::optical_delims_in(ctx.styles())
+            && height < ctx.delimited_sub_formula_min_height()
+        {
+            Abs::zero()
+        } else {
+            height
+        };
+
+        let short_fall = ctx.delimiter_short_fall();
         let mut stretched = glyph.stretch_vertical(ctx, height, short_fall);
         stretched.center_on_axis(ctx);
 
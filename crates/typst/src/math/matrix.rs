@@ -2,15 +2,15 @@ use smallvec::{smallvec, SmallVec};
 
 use crate::diag::{bail, At, SourceResult, StrResult};
 use crate::foundations::{
-    cast, dict, elem, Array, Cast, Content, Dict, Fold, NativeElement, Resolve, Smart,
-    StyleChain, Value,
+    array, cast, dict, elem, Array, Cast, Content, Dict, Fold, NativeElement, Resolve,
+    Smart, StyleChain, Value,
 };
 use crate::layout::{
     Abs, Axes, Em, FixedAlign, Frame, FrameItem, Length, Point, Ratio, Rel, Size,
 };
 use crate::math::{
     alignments, stack, AlignmentResult, FrameFragment, GlyphFragment, LayoutMath,
-    MathContext, Scaled, DELIM_SHORT_FALL,
+    MathContext, MathRow, Scaled,
 };
 use crate::syntax::{Span, Spanned};
 use crate::text::TextElem;
@@ -21,6 +21,7 @@ const DEFAULT_ROW_GAP: Em = Em::new(0.5);
 const DEFAULT_COL_GAP: Em = Em::new(0.5);
 const VERTICAL_PADDING: Ratio = Ratio::new(0.1);
 const DEFAULT_STROKE_THICKNESS: Em = Em::new(0.05);
+const CASES_COLUMN_GAP: Em = Em::new(1.0);
 
 /// A column vector.
 ///
@@ -266,6 +267,9 @@ impl LayoutMath for MatElem {
 /// A case distinction.
 ///
 /// Content across different branches can be aligned with the `&` symbol.
+/// Alternatively, a branch can be given as a value and a condition separated
+/// by a comma and ended with a semicolon. The conditions are then set in their
+/// own left-aligned column. Branches without a condition leave it empty.
 ///
 /// # Example
 /// ```example
@@ -275,6 +279,11 @@ impl LayoutMath for MatElem {
 ///   3 "if" x in NN,
 ///   4 "else",
 /// ) $
+///
+/// $ |x| = cases(
+///   x, "if" x >= 0;
+///   -x, "otherwise";
+/// ) $
 /// ```
 #[elem(LayoutMath)]
 pub struct CasesElem {
@@ -308,28 +317,103 @@ pub struct CasesElem {
 
     /// The branches of the case distinction.
     #[variadic]
-    pub children: Vec<Content>,
+    pub children: Vec<Case>,
 }
 
 impl LayoutMath for CasesElem {
     #[typst_macros::time(name = "math.cases", span = self.span())]
     fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
-        let delim = self.delim(ctx.styles());
-        let frame = layout_vec_body(
-            ctx,
+        let styles = ctx.styles();
+        ctx.layout_cases(
             self.children(),
-            FixedAlign::Start,
-            self.gap(ctx.styles()),
-        )?;
+            self.delim(styles),
+            self.reverse(styles),
+            self.gap(styles),
+            self.span(),
+        )
+    }
+}
 
-        let (open, close) = if self.reverse(ctx.styles()) {
-            (None, Some(delim.close()))
-        } else {
-            (Some(delim.open()), None)
-        };
+/// A branch of a case distinction with an optional condition.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct Case {
+    /// The value of the branch.
+    pub value: Content,
+    /// The condition under which the branch applies.
+    pub condition: Option<Content>,
+}
 
-        layout_delimiters(ctx, frame, open, close, self.span())
+cast! {
+    Case,
+    self => match self.condition {
+        Some(condition) => array![self.value, condition].into_value(),
+        None => self.value.into_value(),
+    },
+    v: Array => match v.as_slice() {
+        [value] => Self { value: value.clone().cast()?, condition: None },
+        [value, condition] => Self {
+            value: value.clone().cast()?,
+            condition: Some(condition.clone().cast()?),
+        },
+        _ => bail!("expected a value and an optional condition"),
+    },
+    v: Content => Self { value: v, condition: None },
+}
+
+/// Lay out the branches of a case distinction with the delimiter in front of
+/// them, or behind them if `reverse` is set. Without conditions, the branches
+/// are stacked like a vector. Otherwise, values and conditions are set in two
+/// left-aligned columns.
+pub(super) fn layout_cases(
+    ctx: &mut MathContext,
+    cases: &[Case],
+    delim: Delimiter,
+    reverse: bool,
+    row_gap: Rel<Abs>,
+    span: Span,
+) -> SourceResult<()> {
+    let (open, close) =
+        if reverse { (None, Some(delim.close())) } else { (Some(delim.open()), None) };
+
+    if cases.iter().all(|case| case.condition.is_none()) {
+        let values: Vec<_> = cases.iter().map(|case| case.value.clone()).collect();
+        let frame = layout_vec_body(ctx, &values, FixedAlign::Start, row_gap)?;
+        return layout_delimiters(ctx, frame, open, close, span);
     }
+
+    let gap = row_gap.relative_to(ctx.regions.base().y);
+    let column_gap = CASES_COLUMN_GAP.scaled(ctx);
+
+    ctx.style(ctx.style.for_denominator());
+    let mut values = vec![];
+    let mut conditions = vec![];
+    for case in cases {
+        values.push(ctx.layout_row(&case.value)?);
+        conditions.push(case.condition.as_ref().map(|c| ctx.layout_row(c)).transpose()?);
+    }
+    ctx.unstyle();
+
+    // Both columns can be aligned further with `&`.
+    let align = |ctx: &MathContext, rows: Vec<MathRow>| -> Vec<Frame> {
+        let AlignmentResult { points, .. } = alignments(&rows);
+        rows.into_iter()
+            .map(|row| row.into_aligned_frame(ctx, &points, FixedAlign::Start))
+            .collect()
+    };
+
+    let missing: Vec<bool> = conditions.iter().map(Option::is_none).collect();
+    let mut present = align(ctx, conditions.into_iter().flatten().collect()).into_iter();
+    let rows = align(ctx, values)
+        .into_iter()
+        .zip(missing)
+        .map(|(value, missing)| {
+            let condition = if missing { None } else { present.next() };
+            std::iter::once(value).chain(condition).collect()
+        })
+        .collect();
+
+    let frame = ctx.layout_matrix(rows, FixedAlign::Start, Axes::new(column_gap, gap));
+    layout_delimiters(ctx, frame, open, close, span)
 }
 
 /// A vector / matrix delimiter.
@@ -550,7 +634,7 @@ fn layout_delimiters(
     span: Span,
 ) -> SourceResult<()> {
     let axis = scaled!(ctx, axis_height);
-    let short_fall = DELIM_SHORT_FALL.scaled(ctx);
+    let short_fall = ctx.delimiter_short_fall();
     let height = frame.height();
     let target = height + VERTICAL_PADDING.of(height);
     frame.set_baseline(height / 2.0 + axis);
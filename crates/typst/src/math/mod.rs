@@ -5,6 +5,7 @@ mod ctx;
 mod accent;
 mod align;
 mod attach;
+mod boxed;
 mod cancel;
 mod class;
 mod equation;
@@ -13,6 +14,7 @@ mod fragment;
 mod lr;
 mod matrix;
 mod op;
+mod phantom;
 mod root;
 mod row;
 mod spacing;
@@ -23,6 +25,7 @@ mod underover;
 pub use self::accent::*;
 pub use self::align::*;
 pub use self::attach::*;
+pub use self::boxed::*;
 pub use self::cancel::*;
 pub use self::class::*;
 pub use self::equation::*;
@@ -30,6 +33,7 @@ pub use self::frac::*;
 pub use self::lr::*;
 pub use self::matrix::*;
 pub use self::op::*;
+pub use self::phantom::*;
 pub use self::root::*;
 pub use self::style::*;
 pub use self::underover::*;
@@ -41,6 +45,8 @@ use self::spacing::*;
 
 use std::borrow::Cow;
 
+use unicode_math_class::MathClass;
+
 use crate::diag::SourceResult;
 use crate::foundations::{
     category, Category, Content, Module, Resolve, Scope, StyleChain,
@@ -162,6 +168,7 @@ pub fn module() -> Module {
     math.define_elem::<TextElem>();
     math.define_elem::<LrElem>();
     math.define_elem::<MidElem>();
+    math.define_elem::<EvaluatedElem>();
     math.define_elem::<AttachElem>();
     math.define_elem::<ScriptsElem>();
     math.define_elem::<LimitsElem>();
@@ -172,8 +179,15 @@ pub fn module() -> Module {
     math.define_elem::<OverbraceElem>();
     math.define_elem::<UnderbracketElem>();
     math.define_elem::<OverbracketElem>();
+    math.define_elem::<XarrowElem>();
+    math.define_elem::<OversetElem>();
+    math.define_elem::<UndersetElem>();
     math.define_elem::<CancelElem>();
+    math.define_elem::<BoxedElem>();
+    math.define_elem::<PhantomElem>();
+    math.define_elem::<SmashElem>();
     math.define_elem::<FracElem>();
+    math.define_elem::<CfracElem>();
     math.define_elem::<BinomElem>();
     math.define_elem::<VecElem>();
     math.define_elem::<MatElem>();
@@ -197,6 +211,8 @@ pub fn module() -> Module {
     math.define_func::<frak>();
     math.define_func::<mono>();
     math.define_func::<bb>();
+    math.define_func::<stylistic_set>();
+    math.define_func::<unsubstituted>();
     math.define_func::<display>();
     math.define_func::<inline>();
     math.define_func::<script>();
@@ -221,96 +237,136 @@ pub trait LayoutMath {
 impl LayoutMath for Content {
     #[typst_macros::time(name = "math", span = self.span())]
     fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
-        // Directly layout the body of nested equations instead of handling it
-        // like a normal equation so that things like this work:
-        // ```
-        // #let my = $pi$
-        // $ my r^2 $
-        // ```
-        if let Some(elem) = self.to::<EquationElem>() {
-            return elem.layout_math(ctx);
-        }
+        // Errors without a span, e.g. from content that was created natively
+        // rather than by an expression, are attributed to the innermost
+        // content that has one.
+        let span = self.span();
+        layout_content(self, ctx).map_err(|mut errors| {
+            if !span.is_detached() {
+                for error in errors.make_mut() {
+                    if error.span.is_detached() {
+                        error.span = span;
+                    }
+                }
+            }
+            errors
+        })
+    }
+}
 
-        if let Some(realized) = ctx.realize(self)? {
-            return realized.layout_math(ctx);
-        }
+/// Whether the children start with a differential, that is, a `d` followed by
+/// a single letter, optionally separated by a space.
+fn is_differential<'a>(mut children: impl Iterator<Item = &'a Content>) -> bool {
+    let text = |child: &Content| child.to::<TextElem>().map(|elem| elem.text().clone());
+    children.next().and_then(text).is_some_and(|text| text == "d")
+        && children
+            .find(|child| !child.is::<SpaceElem>())
+            .and_then(text)
+            .is_some_and(|text| {
+                let mut chars = text.chars();
+                chars.next().is_some_and(char::is_alphabetic) && chars.next().is_none()
+            })
+}
 
-        if self.is_sequence() {
-            let mut bb = BehavedBuilder::new();
-            self.sequence_recursive_for_each(&mut |child: &Content| {
-                bb.push(Cow::Owned(child.clone()), StyleChain::default())
-            });
+/// Lays out content in math, dispatching on its kind.
+fn layout_content(content: &Content, ctx: &mut MathContext) -> SourceResult<()> {
+    // Directly layout the body of nested equations instead of handling it
+    // like a normal equation so that things like this work:
+    // ```
+    // #let my = $pi$
+    // $ my r^2 $
+    // ```
+    if let Some(elem) = content.to::<EquationElem>() {
+        return elem.layout_math(ctx);
+    }
 
-            for (child, _) in bb.finish().0.iter() {
-                child.layout_math(ctx)?;
-            }
-            return Ok(());
-        }
+    if let Some(realized) = ctx.realize(content)? {
+        return realized.layout_math(ctx);
+    }
 
-        if let Some((elem, styles)) = self.to_styled() {
-            if TextElem::font_in(ctx.styles().chain(styles))
-                != TextElem::font_in(ctx.styles())
+    if content.is_sequence() {
+        let mut bb = BehavedBuilder::new();
+        content.sequence_recursive_for_each(&mut |child: &Content| {
+            bb.push(Cow::Owned(child.clone()), StyleChain::default())
+        });
+
+        let children = bb.finish().0.into_items();
+        let auto_dif = EquationElem::auto_dif_in(ctx.styles());
+        for (i, child) in children.iter().enumerate() {
+            if auto_dif
+                && ctx.last_class().is_some_and(|class| class != MathClass::Opening)
+                && is_differential(children[i..].iter().map(|child| &**child))
             {
-                let frame = ctx.layout_content(self)?;
-                ctx.push(FrameFragment::new(ctx, frame).with_spaced(true));
-                return Ok(());
+                ctx.push(SpacingFragment { width: THIN.scaled(ctx), weak: true });
             }
-
-            let prev_map = std::mem::replace(&mut ctx.local, styles.clone());
-            let prev_size = ctx.size;
-            ctx.local.apply(prev_map.clone());
-            ctx.size = TextElem::size_in(ctx.styles());
-            elem.layout_math(ctx)?;
-            ctx.size = prev_size;
-            ctx.local = prev_map;
-            return Ok(());
+            child.layout_math(ctx)?;
         }
+        return Ok(());
+    }
 
-        if self.is::<SpaceElem>() {
-            ctx.push(MathFragment::Space(ctx.space_width.scaled(ctx)));
+    if let Some((elem, styles)) = content.to_styled() {
+        if TextElem::font_in(ctx.styles().chain(styles))
+            != TextElem::font_in(ctx.styles())
+        {
+            let frame = ctx.layout_content(content)?;
+            ctx.push(FrameFragment::new(ctx, frame).with_spaced(true));
             return Ok(());
         }
 
-        if self.is::<LinebreakElem>() {
-            ctx.push(MathFragment::Linebreak);
-            return Ok(());
-        }
+        let prev_map = std::mem::replace(&mut ctx.local, styles.clone());
+        let prev_size = ctx.size;
+        ctx.local.apply(prev_map.clone());
+        ctx.size = TextElem::size_in(ctx.styles());
+        elem.layout_math(ctx)?;
+        ctx.size = prev_size;
+        ctx.local = prev_map;
+        return Ok(());
+    }
 
-        if let Some(elem) = self.to::<HElem>() {
-            if let Spacing::Rel(rel) = elem.amount() {
-                if rel.rel.is_zero() {
-                    ctx.push(SpacingFragment {
-                        width: rel.abs.resolve(ctx.styles()),
-                        weak: elem.weak(ctx.styles()),
-                    });
-                }
-            }
-            return Ok(());
-        }
+    if content.is::<SpaceElem>() {
+        ctx.push(MathFragment::Space(ctx.space_width().scaled(ctx)));
+        return Ok(());
+    }
 
-        if let Some(elem) = self.to::<TextElem>() {
-            let fragment = ctx.layout_text(elem)?;
-            ctx.push(fragment);
-            return Ok(());
-        }
+    if content.is::<LinebreakElem>() {
+        ctx.push(MathFragment::Linebreak);
+        return Ok(());
+    }
 
-        if let Some(boxed) = self.to::<BoxElem>() {
-            let frame = ctx.layout_box(boxed)?;
-            ctx.push(FrameFragment::new(ctx, frame).with_spaced(true));
-            return Ok(());
+    if let Some(elem) = content.to::<HElem>() {
+        if let Spacing::Rel(rel) = elem.amount() {
+            if rel.rel.is_zero() {
+                ctx.push(SpacingFragment {
+                    width: rel.abs.resolve(ctx.styles()),
+                    weak: elem.weak(ctx.styles()),
+                });
+            }
         }
+        return Ok(());
+    }
 
-        if let Some(elem) = self.with::<dyn LayoutMath>() {
-            return elem.layout_math(ctx);
-        }
+    if let Some(elem) = content.to::<TextElem>() {
+        let fragment = ctx.layout_text(elem)?;
+        ctx.push(fragment);
+        return Ok(());
+    }
 
-        let mut frame = ctx.layout_content(self)?;
-        if !frame.has_baseline() {
-            let axis = scaled!(ctx, axis_height);
-            frame.set_baseline(frame.height() / 2.0 + axis);
-        }
+    if let Some(boxed) = content.to::<BoxElem>() {
+        let frame = ctx.layout_box(boxed)?;
         ctx.push(FrameFragment::new(ctx, frame).with_spaced(true));
+        return Ok(());
+    }
 
-        Ok(())
+    if let Some(elem) = content.with::<dyn LayoutMath>() {
+        return elem.layout_math(ctx);
     }
+
+    let mut frame = ctx.layout_content(content)?;
+    if !frame.has_baseline() {
+        let axis = scaled!(ctx, axis_height);
+        frame.set_baseline(frame.height() / 2.0 + axis);
+    }
+    ctx.push(FrameFragment::new(ctx, frame).with_spaced(true));
+
+    Ok(())
 }
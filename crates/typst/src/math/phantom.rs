@@ -0,0 +1,65 @@
+use crate::diag::SourceResult;
+use crate::foundations::{elem, Content, NativeElement};
+use crate::math::{LayoutMath, MathContext};
+
+/// Invisible content that takes up the space of its body, like `\phantom` in
+/// TeX.
+///
+/// This is useful to align parts of equations with each other. Turning off
+/// one of the dimensions gives the equivalents of `\hphantom` and
+/// `\vphantom`.
+///
+/// ```example
+/// $ x + y \
+///   phantom(x) + z $
+/// ```
+#[elem(LayoutMath)]
+pub struct PhantomElem {
+    /// The content whose space to take up.
+    #[required]
+    pub body: Content,
+
+    /// Whether to take up the width of the body.
+    #[default(true)]
+    pub horizontal: bool,
+
+    /// Whether to take up the height of the body.
+    #[default(true)]
+    pub vertical: bool,
+}
+
+impl LayoutMath for PhantomElem {
+    #[typst_macros::time(name = "math.phantom", span = self.span())]
+    fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
+        let styles = ctx.styles();
+        let (horizontal, vertical) = (self.horizontal(styles), self.vertical(styles));
+        let fragment = ctx.layout_phantom(self.body(), horizontal, vertical)?;
+        ctx.push(fragment);
+        Ok(())
+    }
+}
+
+/// Content that is shown, but doesn't take up vertical space, like `\smash`
+/// in TeX.
+///
+/// This keeps tall content from affecting the height of its line or the size
+/// of surrounding delimiters.
+///
+/// ```example
+/// $ sqrt(smash(b/c)) != sqrt(b/c) $
+/// ```
+#[elem(LayoutMath)]
+pub struct SmashElem {
+    /// The content to show.
+    #[required]
+    pub body: Content,
+}
+
+impl LayoutMath for SmashElem {
+    #[typst_macros::time(name = "math.smash", span = self.span())]
+    fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
+        let fragment = ctx.layout_smash(self.body())?;
+        ctx.push(fragment);
+        Ok(())
+    }
+}
@@ -164,7 +164,7 @@ impl MathRow {
             return self.into_line_frame(points, align);
         }
 
-        let leading = if ctx.style.size >= MathSize::Text {
+        let leading = if ctx.current_size() >= MathSize::Text {
             ParElem::leading_in(ctx.styles())
         } else {
             TIGHT_LEADING.scaled(ctx)
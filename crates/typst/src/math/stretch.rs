@@ -61,9 +61,10 @@ fn stretch_glyph(
         return base.into_variant();
     }
 
-    // Search for a pre-made variant with a good advance.
+    // Search for a pre-made variant with a good advance. If none is large
+    // enough, fall back to assembling the glyph from its parts below.
     let mut best_id = base.id;
-    let mut best_advance = base.width;
+    let mut best_advance = advance;
     for variant in construction.variants {
         best_id = variant.variant_glyph;
         best_advance = base.font.to_em(variant.advance_measurement).at(base.font_size);
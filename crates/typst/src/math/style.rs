@@ -4,6 +4,7 @@ use crate::diag::SourceResult;
 use crate::foundations::{elem, func, Cast, Content, NativeElement, Smart, StyleChain};
 use crate::math::{LayoutMath, MathContext};
 use crate::syntax::Span;
+use crate::text::StylisticSet;
 
 /// Bold font style in math.
 ///
@@ -158,6 +159,52 @@ pub fn bb(
         .pack()
 }
 
+/// A stylistic set of the math font for a part of an equation.
+///
+/// Math fonts can provide alternative forms of some glyphs in stylistic sets.
+/// In contrast to the text's [`stylistic-set`]($text.stylistic-set) property,
+/// this only affects the wrapped content.
+///
+/// ```example
+/// $ cal(H) != #math.stylistic-set(1)[$cal(H)$] $
+/// ```
+#[func(title = "Stylistic Set")]
+pub fn stylistic_set(
+    /// The call span of this function.
+    span: Span,
+    /// The stylistic set to apply, between 1 and 20.
+    set: StylisticSet,
+    /// The content to style.
+    body: Content,
+) -> Content {
+    MathStyleElem::new(body)
+        .spanned(span)
+        .with_stylistic_set(Some(set))
+        .pack()
+}
+
+/// Glyphs of the math font as they are, without substitutions.
+///
+/// This turns off the font's glyph-wise OpenType features for a part of an
+/// equation, like stylistic sets and the smaller alternates of scripts. Text
+/// that is shaped as a whole, like multi-letter words, is not affected.
+///
+/// ```example
+/// $ #math.stylistic-set(1)[$cal(H) != unsubstituted(cal(H))$] $
+/// ```
+#[func(title = "Unsubstituted")]
+pub fn unsubstituted(
+    /// The call span of this function.
+    span: Span,
+    /// The content to style.
+    body: Content,
+) -> Content {
+    MathStyleElem::new(body)
+        .spanned(span)
+        .with_substitutions(Some(false))
+        .pack()
+}
+
 /// Forced display style in math.
 ///
 /// This is the normal size for block equations.
@@ -285,12 +332,18 @@ pub struct MathStyleElem {
 
     /// Whether to limit height of exponents
     pub cramped: Option<bool>,
+
+    /// Which stylistic set of the math font to apply.
+    pub stylistic_set: Option<StylisticSet>,
+
+    /// Whether to apply the glyph-wise substitutions of the math font.
+    pub substitutions: Option<bool>,
 }
 
 impl LayoutMath for MathStyleElem {
     #[typst_macros::time(name = "math.style", span = self.span())]
     fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
-        let mut style = ctx.style;
+        let mut style = ctx.current_style();
         if let Some(variant) = self.variant(StyleChain::default()) {
             style = style.with_variant(variant);
         }
@@ -307,7 +360,18 @@ impl LayoutMath for MathStyleElem {
             style = style.with_cramped(cramped);
         }
         ctx.style(style);
-        self.body().layout_math(ctx)?;
+        let set = self.stylistic_set(StyleChain::default());
+        let layout = |ctx: &mut MathContext| match set {
+            Some(set) => {
+                let tag = [b's', b's', b'0' + set.get() / 10, b'0' + set.get() % 10];
+                ctx.with_feature(tag, |ctx| self.body().layout_math(ctx))
+            }
+            None => self.body().layout_math(ctx),
+        };
+        match self.substitutions(StyleChain::default()) {
+            Some(false) => ctx.without_substitutions(layout)?,
+            _ => layout(ctx)?,
+        }
         ctx.unstyle();
         Ok(())
     }
@@ -603,6 +667,15 @@ fn greek_exception(
     italic: bool,
 ) -> Option<char> {
     use MathVariant::*;
+
+    // Digamma only exists in bold, so bold italic falls back to bold instead
+    // of the regular letter.
+    match (c, variant, bold) {
+        ('Ϝ', Serif, true) => return Some('𝟊'),
+        ('ϝ', Serif, true) => return Some('𝟋'),
+        _ => {}
+    }
+
     let list = match c {
         'ϴ' => ['𝚹', '𝛳', '𝜭', '𝝧', '𝞡'],
         '∇' => ['𝛁', '𝛻', '𝜵', '𝝯', '𝞩'],
@@ -625,3 +698,49 @@ fn greek_exception(
         _ => return None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bold_italic(variant: MathVariant) -> MathStyle {
+        MathStyle {
+            variant,
+            size: MathSize::Text,
+            class: Smart::Auto,
+            cramped: false,
+            bold: true,
+            italic: Smart::Custom(true),
+        }
+    }
+
+    #[test]
+    fn test_styled_char_bold_italic_latin() {
+        let style = bold_italic(MathVariant::Serif);
+        assert_eq!(styled_char(style, 'A'), '𝑨');
+        assert_eq!(styled_char(style, 'Z'), '𝒁');
+        assert_eq!(styled_char(style, 'a'), '𝒂');
+        assert_eq!(styled_char(style, 'h'), '𝒉');
+        assert_eq!(styled_char(style, 'z'), '𝒛');
+
+        let style = bold_italic(MathVariant::Sans);
+        assert_eq!(styled_char(style, 'A'), '𝘼');
+        assert_eq!(styled_char(style, 'a'), '𝙖');
+    }
+
+    #[test]
+    fn test_styled_char_bold_italic_greek() {
+        let style = bold_italic(MathVariant::Serif);
+        assert_eq!(styled_char(style, 'Α'), '𝜜');
+        assert_eq!(styled_char(style, 'Ω'), '𝜴');
+        assert_eq!(styled_char(style, 'α'), '𝜶');
+        assert_eq!(styled_char(style, 'ω'), '𝝎');
+        assert_eq!(styled_char(style, 'ϑ'), '𝝑');
+        assert_eq!(styled_char(style, '∂'), '𝝏');
+        assert_eq!(styled_char(style, 'ϝ'), '𝟋');
+
+        let style = bold_italic(MathVariant::Sans);
+        assert_eq!(styled_char(style, 'Α'), '𝞐');
+        assert_eq!(styled_char(style, 'α'), '𝞪');
+    }
+}
@@ -1,11 +1,11 @@
 use unicode_math_class::MathClass;
 
-use crate::diag::SourceResult;
-use crate::foundations::{elem, Content, NativeElement};
-use crate::layout::{Abs, Em, FixedAlign, Frame, FrameItem, Point, Size};
+use crate::diag::{bail, SourceResult};
+use crate::foundations::{cast, elem, Content, NativeElement, Value};
+use crate::layout::{Abs, Em, FixedAlign, Frame, FrameItem, Length, Point, Size};
 use crate::math::{
     alignments, AlignmentResult, FrameFragment, GlyphFragment, LayoutMath, MathContext,
-    MathRow, Scaled,
+    MathFragment, MathRow, Scaled,
 };
 use crate::syntax::Span;
 use crate::text::TextElem;
@@ -13,6 +13,8 @@ use crate::visualize::{FixedStroke, Geometry};
 
 const BRACE_GAP: Em = Em::new(0.25);
 const BRACKET_GAP: Em = Em::new(0.25);
+const ARROW_GAP: Em = Em::new(0.15);
+const ARROW_PAD: Em = Em::new(0.3);
 
 /// A marker to distinguish under- vs. overlines.
 enum LineKind {
@@ -244,6 +246,194 @@ impl LayoutMath for OverbracketElem {
     }
 }
 
+/// Content set over a base, like `\overset` in LaTeX.
+///
+/// The content on top is set in script size and centered over the base. The
+/// result is spaced like the base, so a relation stays a relation.
+///
+/// ```example
+/// $ f(x) overset("def", =) x^2 $
+/// ```
+#[elem(LayoutMath)]
+pub struct OversetElem {
+    /// The content above the base.
+    #[required]
+    pub top: Content,
+
+    /// The base.
+    #[required]
+    pub base: Content,
+}
+
+impl LayoutMath for OversetElem {
+    #[typst_macros::time(name = "math.overset", span = self.span())]
+    fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
+        ctx.layout_overset(self.top(), self.base())
+    }
+}
+
+/// Content set under a base, like `\underset` in LaTeX.
+///
+/// The content at the bottom is set in script size and centered under the
+/// base. The result is spaced like the base.
+///
+/// ```example
+/// $ underset(x -> 0, lim) f(x) $
+/// ```
+#[elem(LayoutMath)]
+pub struct UndersetElem {
+    /// The content below the base.
+    #[required]
+    pub bottom: Content,
+
+    /// The base.
+    #[required]
+    pub base: Content,
+}
+
+impl LayoutMath for UndersetElem {
+    #[typst_macros::time(name = "math.underset", span = self.span())]
+    fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
+        ctx.layout_underset(self.bottom(), self.base())
+    }
+}
+
+/// An arrow with optional labels above and below it.
+///
+/// The arrow is stretched to be a bit wider than its labels and is spaced like
+/// a relation. Other extensible relations, like equals signs and harpoons,
+/// work the same way.
+///
+/// ```example
+/// $ x xarrow(|->, over: f) f(x) $
+/// $ A xarrow(->, over: "isomorphism", under: phi) B $
+/// $ a xarrow(=, over: "by definition") b $
+/// ```
+#[elem(title = "Labelled Arrow", LayoutMath)]
+pub struct XarrowElem {
+    /// The arrow, like `->` or `|->`, or another relation symbol, like `=`.
+    #[required]
+    pub arrow: Arrow,
+
+    /// The label above the arrow.
+    pub over: Option<Content>,
+
+    /// The label below the arrow.
+    pub under: Option<Content>,
+
+    /// The minimum width of the arrow. It is stretched to be at least this
+    /// wide even if it has no or only short labels.
+    ///
+    /// ```example
+    /// $ a xarrow(=, min-width: #3em) b $
+    /// ```
+    #[resolve]
+    pub min_width: Length,
+}
+
+impl LayoutMath for XarrowElem {
+    #[typst_macros::time(name = "math.xarrow", span = self.span())]
+    fn layout_math(&self, ctx: &mut MathContext) -> SourceResult<()> {
+        let styles = ctx.styles();
+        ctx.layout_xarrow(
+            self.arrow().0,
+            self.over(styles).as_ref(),
+            self.under(styles).as_ref(),
+            self.min_width(styles),
+            self.span(),
+        )
+    }
+}
+
+/// An arrow or another relation symbol that can be stretched horizontally.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Arrow(pub char);
+
+cast! {
+    Arrow,
+    self => self.0.into_value(),
+    v: char => {
+        if unicode_math_class::class(v) != Some(MathClass::Relation) {
+            bail!("expected an arrow or another relation symbol");
+        }
+        Self(v)
+    },
+    v: Content => match v.to::<TextElem>() {
+        Some(elem) => Value::Str(elem.text().clone().into()).cast()?,
+        None => bail!("expected text"),
+    },
+}
+
+/// Layout an arrow with labels above and below it. The arrow is stretched to
+/// the width of the wider label plus some padding, but at least to
+/// `min_width`, and the labels are set in script size.
+pub(super) fn layout_xarrow(
+    ctx: &mut MathContext,
+    c: char,
+    over: Option<&Content>,
+    under: Option<&Content>,
+    min_width: Abs,
+    span: Span,
+) -> SourceResult<()> {
+    let gap = ARROW_GAP.scaled(ctx);
+    let pad = ARROW_PAD.scaled(ctx);
+
+    ctx.style(ctx.style.for_superscript());
+    let over = over.map(|over| ctx.layout_row(over)).transpose()?;
+    ctx.unstyle();
+
+    ctx.style(ctx.style.for_subscript());
+    let under = under.map(|under| ctx.layout_row(under)).transpose()?;
+    ctx.unstyle();
+
+    let width = [&over, &under]
+        .into_iter()
+        .flatten()
+        .map(|row| row.iter().map(MathFragment::width).sum::<Abs>())
+        .fold(Abs::zero(), Abs::max);
+    let glyph = GlyphFragment::new(ctx, c, span);
+    let target = (width + 2.0 * pad).max(min_width);
+    let arrow = glyph.stretch_horizontal(ctx, target, Abs::zero());
+
+    let baseline = over.is_some() as usize;
+    let rows: Vec<MathRow> =
+        over.into_iter().chain([arrow.into()]).chain(under).collect();
+    let frame = stack(ctx, rows, FixedAlign::Center, gap, baseline);
+    ctx.push(FrameFragment::new(ctx, frame).with_class(MathClass::Relation));
+
+    Ok(())
+}
+
+/// Layout an annotation in script size centered over or under a base. The
+/// gap between them is the font's minimum gap for limits.
+pub(super) fn layout_underoverset(
+    ctx: &mut MathContext,
+    annotation: &Content,
+    base: &Content,
+    over: bool,
+) -> SourceResult<()> {
+    let base = ctx.layout_row(base)?;
+    let class = base.class();
+
+    ctx.style(if over { ctx.style.for_superscript() } else { ctx.style.for_subscript() });
+    let mut rows = ctx.layout_row(annotation)?.rows();
+    ctx.unstyle();
+
+    let (gap, baseline) = if over {
+        let baseline = rows.len();
+        rows.push(base);
+        (scaled!(ctx, upper_limit_gap_min), baseline)
+    } else {
+        rows.insert(0, base);
+        (scaled!(ctx, lower_limit_gap_min), 0)
+    };
+
+    let frame = stack(ctx, rows, FixedAlign::Center, gap, baseline);
+    ctx.push(FrameFragment::new(ctx, frame).with_class(class));
+
+    Ok(())
+}
+
 /// Layout an over- or underbrace-like object.
 fn layout_underoverspreader(
     ctx: &mut MathContext,
@@ -193,6 +193,32 @@ pub struct FigureElem {
     #[default(Some(NumberingPattern::from_str("1").unwrap().into()))]
     pub numbering: Option<Numbering>,
 
+    /// Whether this figure is a sub-figure of an enclosing figure.
+    ///
+    /// Sub-figures don't step the counter of their kind. Instead, they are
+    /// numbered within the closest preceding regular figure of the same kind,
+    /// using their own numbering. References to a sub-figure show the parent's
+    /// number followed by the sub-figure's, like "Figure 1b".
+    ///
+    /// ```example
+    /// #figure(
+    ///   {
+    ///     set figure(sub: true, numbering: "(a)")
+    ///     grid(
+    ///       columns: 2,
+    ///       gutter: 1em,
+    ///       [#figure([Left], caption: [Left]) <left>],
+    ///       [#figure([Right], caption: [Right]) <right>],
+    ///     )
+    ///   },
+    ///   caption: [Both sides],
+    /// ) <both>
+    ///
+    /// @both consists of @left and @right.
+    /// ```
+    #[default(false)]
+    pub sub: bool,
+
     /// The vertical gap between the body and caption.
     #[default(Em::new(0.65).into())]
     pub gap: Length,
@@ -220,6 +246,36 @@ impl FigureElem {
     type FigureCaption;
 }
 
+impl FigureElem {
+    /// The numbers that a reference to this figure displays if it is a
+    /// sub-figure: its parent's number followed by its own, like "2b".
+    pub(crate) fn sub_ref_numbers(
+        &self,
+        engine: &mut Engine,
+    ) -> SourceResult<Option<Content>> {
+        let (Some(location), Some(numbering)) =
+            (self.location(), self.numbering(StyleChain::default()))
+        else {
+            return Ok(None);
+        };
+
+        let Some((parent, index)) = sub_position(engine, location) else {
+            return Ok(None);
+        };
+
+        let mut numbers = Content::empty();
+        if let (Some(parent_numbering), Some(parent_location)) =
+            (parent.numbering(StyleChain::default()), parent.location())
+        {
+            numbers = Refable::counter(&parent)
+                .at(engine, parent_location)?
+                .display(engine, &parent_numbering.trimmed())?;
+        }
+
+        Ok(Some(numbers + numbering.trimmed().apply(engine, &[index])?.display()))
+    }
+}
+
 impl Synthesize for FigureElem {
     fn synthesize(
         &mut self,
@@ -269,7 +325,7 @@ impl Synthesize for FigureElem {
                 };
 
                 let target = descendant.unwrap_or_else(|| Cow::Borrowed(self.body()));
-                Some(supplement.resolve(engine, [target])?)
+                Some(supplement.resolve(engine, self.span(), [target])?)
             }
         };
 
@@ -292,6 +348,7 @@ impl Synthesize for FigureElem {
         self.push_kind(Smart::Custom(kind));
         self.push_supplement(Smart::Custom(supplement.map(Supplement::Content)));
         self.push_numbering(numbering);
+        self.push_sub(self.sub(styles));
         self.push_outlined(self.outlined(styles));
         self.push_counter(Some(counter));
 
@@ -343,6 +400,11 @@ impl Finalize for FigureElem {
 
 impl Count for FigureElem {
     fn update(&self) -> Option<CounterUpdate> {
+        // Sub-figures are numbered within their parent instead.
+        if self.sub(StyleChain::default()) {
+            return None;
+        }
+
         // If the figure is numbered, step the counter by one.
         // This steps the `counter(figure)` which is global to all numbered figures.
         self.numbering(StyleChain::default())
@@ -391,7 +453,7 @@ impl Outlinable for FigureElem {
             self.numbering(StyleChain::default()),
         ) {
             let location = self.location().unwrap();
-            let numbers = counter.at(engine, location)?.display(engine, &numbering)?;
+            let numbers = display_numbers(engine, &counter, &numbering, location)?;
 
             if !supplement.is_empty() {
                 supplement += TextElem::packed('\u{a0}');
@@ -406,6 +468,56 @@ impl Outlinable for FigureElem {
     }
 }
 
+/// Display the number of the figure at the given location.
+///
+/// Regular figures display their counter, while sub-figures display their
+/// position within their parent figure.
+fn display_numbers(
+    engine: &mut Engine,
+    counter: &Counter,
+    numbering: &Numbering,
+    location: Location,
+) -> SourceResult<Content> {
+    if let Some((_, index)) = sub_position(engine, location) {
+        return Ok(numbering.apply(engine, &[index])?.display());
+    }
+
+    counter.at(engine, location)?.display(engine, numbering)
+}
+
+/// If the figure at the given location is a sub-figure, find its parent figure
+/// and its one-based position among the parent's sub-figures.
+fn sub_position(engine: &Engine, location: Location) -> Option<(FigureElem, usize)> {
+    let elem = engine.introspector.query_first(&Selector::Location(location))?;
+    let figure = elem.to::<FigureElem>()?;
+    if !figure.sub(StyleChain::default()) {
+        return None;
+    }
+
+    let Smart::Custom(kind) = figure.kind(StyleChain::default()) else {
+        return None;
+    };
+
+    // The figures of the kind are queried once for all sub-figures and this
+    // one is found in them by its location, so that finding the positions of
+    // many sub-figures doesn't take quadratic time.
+    let selector = select_where!(FigureElem, Kind => kind);
+    let figures = engine.introspector.query(&selector);
+    let end = engine.introspector.count_before(&selector, location, true);
+
+    let mut index = 0;
+    for elem in figures[..end].iter().rev() {
+        let figure = elem.to::<FigureElem>()?;
+        if figure.sub(StyleChain::default()) {
+            index += 1;
+        } else {
+            return Some((figure.clone(), index));
+        }
+    }
+
+    None
+}
+
 /// The caption of a figure. This element can be used in set and show rules to
 /// customize the appearance of captions for all figures or figures of a
 /// specific kind.
@@ -563,7 +675,7 @@ impl Show for FigureCaption {
             self.counter(),
             self.figure_location(),
         ) {
-            let numbers = counter.at(engine, *location)?.display(engine, numbering)?;
+            let numbers = display_numbers(engine, counter, numbering, *location)?;
             if !supplement.is_empty() {
                 supplement += TextElem::packed('\u{a0}');
             }
@@ -137,7 +137,7 @@ impl Synthesize for HeadingElem {
             Smart::Auto => TextElem::packed(Self::local_name_in(styles)),
             Smart::Custom(None) => Content::empty(),
             Smart::Custom(Some(supplement)) => {
-                supplement.resolve(engine, [self.clone()])?
+                supplement.resolve(engine, self.span(), [self.clone()])?
             }
         };
 
@@ -5,11 +5,13 @@ use crate::diag::{bail, At, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, elem, scope, select_where, Content, Finalize, Func, LocatableSelector,
-    NativeElement, Show, Smart, StyleChain,
+    NativeElement, Show, Smart, StyleChain, Synthesize,
 };
 use crate::introspection::{Counter, CounterKey, Locatable};
 use crate::layout::{BoxElem, Fr, HElem, HideElem, Length, Rel, RepeatElem, Spacing};
-use crate::model::{Destination, HeadingElem, NumberingPattern, ParbreakElem, Refable};
+use crate::model::{
+    Destination, HeadingElem, Numbering, NumberingPattern, ParbreakElem, Refable,
+};
 use crate::syntax::Span;
 use crate::text::{Lang, LinebreakElem, LocalName, Region, SpaceElem, TextElem};
 use crate::util::{option_eq, NonZeroExt};
@@ -57,7 +59,15 @@ use crate::util::{option_eq, NonZeroExt};
 /// `title` and `indent` parameters. If desired, however, it is possible to have
 /// more control over the outline's look and style through the
 /// [`outline.entry`]($outline.entry) element.
-#[elem(scope, keywords = ["Table of Contents"], Show, Finalize, LocalName)]
+#[elem(
+    scope,
+    keywords = ["Table of Contents"],
+    Synthesize,
+    Show,
+    Finalize,
+    LocalName,
+    Refable
+)]
 pub struct OutlineElem {
     /// The title of the outline.
     ///
@@ -185,6 +195,20 @@ impl OutlineElem {
     type OutlineEntry;
 }
 
+impl Synthesize for OutlineElem {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        let title = self.title(styles).map(|title| {
+            title.or_else(|| {
+                Smart::Custom(
+                    TextElem::packed(Self::local_name_in(styles)).spanned(self.span()),
+                )
+            })
+        });
+        self.push_title(title);
+        Ok(())
+    }
+}
+
 impl Show for OutlineElem {
     #[typst_macros::time(name = "outline", span = self.span())]
     fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
@@ -250,6 +274,24 @@ impl Show for OutlineElem {
     }
 }
 
+impl Refable for OutlineElem {
+    fn supplement(&self) -> Content {
+        // After synthesis, the title is custom content unless it is disabled.
+        match self.title(StyleChain::default()) {
+            Some(Smart::Custom(title)) => title,
+            _ => TextElem::packed(Self::local_name(Lang::ENGLISH, None)),
+        }
+    }
+
+    fn counter(&self) -> Counter {
+        Counter::of(Self::elem())
+    }
+
+    fn numbering(&self) -> Option<Numbering> {
+        None
+    }
+}
+
 impl Finalize for OutlineElem {
     fn finalize(&self, realized: Content, _: StyleChain) -> Content {
         realized
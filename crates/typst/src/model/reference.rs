@@ -1,17 +1,22 @@
-use ecow::eco_format;
+use std::str::FromStr;
 
-use crate::diag::{bail, At, Hint, SourceResult};
+use ecow::{eco_format, EcoString, EcoVec};
+
+use crate::diag::{bail, At, Hint, SourceDiagnostic, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, Content, Func, IntoValue, Label, NativeElement, Show, Smart, StyleChain,
-    Synthesize,
+    cast, elem, Content, Dict, Func, IntoValue, Label, NativeElement, Resolve, Selector,
+    Show, Smart, StyleChain, Synthesize, Value,
 };
-use crate::introspection::{Counter, Locatable};
+use crate::introspection::{Counter, CounterKey, CounterState, Locatable, Location};
+use crate::layout::Length;
 use crate::math::EquationElem;
 use crate::model::{
-    BibliographyElem, CiteElem, Destination, Figurable, FootnoteElem, Numbering,
+    BibliographyElem, CiteElem, Destination, Figurable, FigureElem, FigureKind,
+    FootnoteElem, HeadingElem, Numbering, NumberingPattern, OutlineElem,
 };
-use crate::text::TextElem;
+use crate::syntax::Span;
+use crate::text::{FontStyle, SpaceElem, TextElem};
 
 /// A reference to a label or bibliography.
 ///
@@ -22,10 +27,13 @@ use crate::text::TextElem;
 /// bibliography.
 ///
 /// Referenceable elements include [headings]($heading), [figures]($figure),
-/// [equations]($math.equation), and [footnotes]($footnote). To create a custom
-/// referenceable element like a theorem, you can create a figure of a custom
-/// [`kind`]($figure.kind) and write a show rule for it. In the future, there
-/// might be a more direct way to define a custom referenceable element.
+/// [equations]($math.equation), [footnotes]($footnote), and
+/// [outlines]($outline). A reference to an outline displays its title and a
+/// reference to an equation with a [`tag`]($math.equation.tag) displays that
+/// tag. To create a custom referenceable element like a theorem, you can create
+/// a figure of a custom [`kind`]($figure.kind) and write a show rule for it. In
+/// the future, there might be a more direct way to define a custom
+/// referenceable element.
 ///
 /// If you just want to link to a labelled element and not get an automatic
 /// textual reference, consider using the [`link`]($link) function instead.
@@ -68,6 +76,14 @@ use crate::text::TextElem;
 /// be `{none}` even if it exists if Typst hasn't discovered it yet, so you
 /// always need to handle that case in your code.
 ///
+/// Typst lays out the document repeatedly until all references are resolved.
+/// Only the first of these passes can't see any elements, so in the final
+/// document, `element` is populated for every label that exists in it, no
+/// matter whether it is defined before or after the reference. It stays
+/// `{none}` for references to [bibliography]($bibliography) entries and when
+/// the document doesn't converge, for example because a show rule keeps
+/// changing what a reference refers to.
+///
 /// ```example
 /// #set heading(numbering: "1.")
 /// #set math.equation(numbering: "(1)")
@@ -127,6 +143,226 @@ pub struct RefElem {
     #[borrowed]
     pub supplement: Smart<Option<Supplement>>,
 
+    /// Default supplements for references to specific kinds of elements.
+    ///
+    /// The dictionary maps element names to supplements, which can be content
+    /// or functions like the [`supplement`]($ref.supplement). It is only
+    /// consulted if the supplement is `{auto}` and elements that don't appear
+    /// in it keep their own supplement. Figures of a custom
+    /// [`kind`]($figure.kind) are looked up by their kind first, so that
+    /// theorems and the like can be told apart from other figures.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #set ref(supplements: (figure: "Fig.", heading: "Sec."))
+    ///
+    /// = Introduction <intro>
+    /// #figure([A], caption: [B]) <fig>
+    /// See @intro and @fig.
+    /// ```
+    #[borrowed]
+    pub supplements: Dict,
+
+    /// Default numberings for references to specific kinds of elements.
+    ///
+    /// Like the [`supplements`]($ref.supplements), the dictionary maps element
+    /// names and custom figure kinds to [numberings]($numbering), which are
+    /// used in references instead of the element's own numbering. The
+    /// numbering of the element itself is left unchanged.
+    ///
+    /// ```example
+    /// #set ref(
+    ///   supplements: (theorem: [Thm.]),
+    ///   numberings: (theorem: "I"),
+    /// )
+    ///
+    /// #figure(
+    ///   [$a^2 + b^2 = c^2$],
+    ///   kind: "theorem",
+    ///   supplement: [Theorem],
+    /// ) <pythagoras>
+    ///
+    /// By @pythagoras, ...
+    /// ```
+    #[borrowed]
+    pub numberings: Dict,
+
+    /// The font style of the supplement.
+    ///
+    /// By default, the supplement inherits the style of the surrounding text.
+    /// Set this to `{"normal"}` to keep supplements upright in italic
+    /// contexts, like theorem statements.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #set ref(supplement-style: "normal")
+    ///
+    /// = Introduction <intro>
+    /// _By the results of @intro._
+    /// ```
+    pub supplement_style: Option<FontStyle>,
+
+    /// An alternative description of the reference's link.
+    ///
+    /// The visible reference text stays the same, but exporters that support
+    /// it (like PDF) show this text as the link's tooltip instead.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    ///
+    /// = Introduction <intro>
+    /// Read @intro or
+    /// #ref(<intro>, alt: "Jump to the introduction").
+    /// ```
+    pub alt: Option<EcoString>,
+
+    /// Whether to derive the link's tooltip from the referenced element if no
+    /// [`alt`]($ref.alt) text is given.
+    ///
+    /// The tooltip combines the reference text with the title of the element,
+    /// for example "Section 1: Introduction" for a heading or the caption for
+    /// a figure. Elements without a title don't get a tooltip.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #set ref(tooltip: true)
+    ///
+    /// = Introduction <intro>
+    /// Hover over @intro in the PDF.
+    /// ```
+    #[default(false)]
+    pub tooltip: bool,
+
+    /// How far below the start of the referenced element the reference's
+    /// link should land.
+    ///
+    /// By default, the link jumps to the top of the element. For long
+    /// elements, this lets you point the link at a spot further down.
+    ///
+    /// ```example
+    /// #set math.equation(numbering: "(1)")
+    ///
+    /// $ a + b = c $ <sum>
+    /// See #ref(<sum>, offset: 1em).
+    /// ```
+    pub offset: Length,
+
+    /// Whether the reference links to the referenced element.
+    ///
+    /// Disable this to get just the text of the reference, for example for
+    /// documents that are meant to be printed.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    ///
+    /// = Introduction <intro>
+    /// See #ref(<intro>, link: false).
+    /// ```
+    #[default(true)]
+    pub link: bool,
+
+    /// The label of the element that ends a reference range.
+    ///
+    /// If set, the reference shows the numbers of the referenced element and
+    /// of this one, separated by the [`range-separator`]($ref.range-separator).
+    /// Both must be counted by the same counter. Since the numbers are
+    /// displayed with the elements' numbering, ranges work for any numbering,
+    /// like letters for appendices.
+    ///
+    /// The numbers of a range are kept on one line, but unlike for single
+    /// numbers, a line may break between the supplement and the range.
+    ///
+    /// ```example
+    /// #set heading(numbering: "A.", supplement: [Appendix])
+    ///
+    /// See #ref(<a>, to: <c>, supplement: [Appendices]).
+    ///
+    /// = Data <a>
+    /// = Code <b>
+    /// = Proofs <c>
+    /// ```
+    pub to: Option<Label>,
+
+    /// The separator between the start and the end of a
+    /// [reference range]($ref.to). Defaults to an en dash.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #set ref(range-separator: [ to ])
+    ///
+    /// See #ref(<a>, to: <b>).
+    ///
+    /// = Data <a>
+    /// = Code <b>
+    /// ```
+    #[default(TextElem::packed('–'))]
+    pub range_separator: Content,
+
+    /// Whether to append the page of the referenced element in parentheses,
+    /// as in "Section 1 (p. 5)".
+    ///
+    /// The page number is displayed with the numbering of the page the element
+    /// is on, or with arabic numerals if that page isn't numbered.
+    ///
+    /// ```example
+    /// #set page(numbering: "1")
+    /// #set heading(numbering: "1.")
+    /// #set ref(show-page: true)
+    ///
+    /// = Introduction <intro>
+    /// #pagebreak()
+    /// See @intro.
+    /// ```
+    #[default(false)]
+    pub show_page: bool,
+
+    /// The supplement placed before the page number when
+    /// [`show-page`]($ref.show-page) is enabled.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #set ref(show-page: true, page-supplement: [page])
+    ///
+    /// = Introduction <intro>
+    /// See @intro.
+    /// ```
+    #[default(TextElem::packed("p."))]
+    pub page_supplement: Content,
+
+    /// A template for the reference's text.
+    ///
+    /// The placeholders `{supplement}`, `{number}`, and `{page}` are replaced
+    /// with the reference's supplement, the referenced element's number, and
+    /// the number of the page it is on. When set, the template takes
+    /// precedence over [`show-page`]($ref.show-page).
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    ///
+    /// = Introduction <intro>
+    /// See #ref(<intro>, format: "{supplement} {number} on page {page}").
+    /// ```
+    pub format: Option<EcoString>,
+
+    /// What to show instead of a reference that targets the same element as
+    /// the reference directly before it, like "ibid." in some citation
+    /// styles.
+    ///
+    /// The first of several consecutive references to an element is shown as
+    /// usual. Repeated references still link to the element. Ranges only
+    /// count as repeated if they also end at the same element.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #set ref(ibid: [ibid.])
+    ///
+    /// = Introduction <intro>
+    /// = Results <results>
+    /// See @intro. As argued in @intro,
+    /// it follows from @results.
+    /// ```
+    pub ibid: Option<Content>,
+
     /// A synthesized citation.
     #[synthesized]
     pub citation: Option<CiteElem>,
@@ -134,6 +370,28 @@ pub struct RefElem {
     /// The referenced element.
     #[synthesized]
     pub element: Option<Content>,
+
+    /// The value of the referenced element's counter at the element's
+    /// location, as an array of integers.
+    ///
+    /// This saves show rules from looking the value up themselves. It is
+    /// `{none}` for citations and as long as the element hasn't been
+    /// discovered yet.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.a")
+    /// #show ref: it => {
+    ///   if it.counter-at != none [
+    ///     #it (level #it.counter-at.len())
+    ///   ] else { it }
+    /// }
+    ///
+    /// = Introduction
+    /// == Details <details>
+    /// See @details.
+    /// ```
+    #[synthesized]
+    pub counter_at: Option<CounterState>,
 }
 
 impl Synthesize for RefElem {
@@ -145,11 +403,25 @@ impl Synthesize for RefElem {
         let citation = self.to_citation(engine, styles)?;
         self.push_citation(Some(citation));
         self.push_element(None);
+        self.push_counter_at(None);
+        self.push_alt(self.alt(styles));
+        self.push_offset(self.offset(styles));
+        self.push_link(self.link(styles));
+        self.push_to(self.to(styles));
 
         let target = *self.target();
         if !BibliographyElem::has(engine, target) {
             if let Ok(elem) = engine.introspector.query_label(target).cloned() {
-                self.push_element(Some(elem.into_inner()));
+                let elem = elem.into_inner();
+                if self.alt(styles).is_none() && self.tooltip(styles) {
+                    self.push_alt(tooltip(engine, &elem)?);
+                }
+                if let (Some(refable), Some(loc)) =
+                    (elem.with::<dyn Refable>(), elem.location())
+                {
+                    self.push_counter_at(Some(refable.counter().at(engine, loc)?));
+                }
+                self.push_element(Some(elem));
                 return Ok(());
             }
         }
@@ -171,6 +443,10 @@ impl Show for RefElem {
                     bail!(span, "label occurs in the document and its bibliography");
                 }
 
+                if self.to(styles).is_some() {
+                    bail!(span, "cannot reference a range of citations");
+                }
+
                 return Ok(self.to_citation(engine, styles)?.spanned(span).pack());
             }
 
@@ -195,48 +471,348 @@ impl Show for RefElem {
                 })
                 .at(span)?;
 
-            let numbering = refable
-                .numbering()
-                .ok_or_else(|| {
-                    eco_format!(
-                        "cannot reference {} without numbering",
-                        elem.func().name()
-                    )
-                })
-                .hint(eco_format!(
-                    "you can enable {} numbering with `#set {}(numbering: \"1.\")`",
-                    elem.func().name(),
-                    if elem.func() == EquationElem::elem() {
-                        "math.equation"
-                    } else {
-                        elem.func().name()
-                    }
-                ))
-                .at(span)?;
-
             let loc = elem.location().unwrap();
-            let numbers = refable
-                .counter()
-                .at(engine, loc)?
-                .display(engine, &numbering.trimmed())?;
+
+            // Whether the numbers form a range, which may wrap after the
+            // supplement.
+            let mut ranged = false;
+
+            // Outlines exist only once per kind and are referred to by their
+            // supplement alone. Elements with a custom tag are referred to by
+            // that tag instead of their number and sub-figures by their
+            // parent's number followed by their own.
+            let numbers = if elem.func() == OutlineElem::elem() {
+                None
+            } else if let Some(tag) = refable.tag() {
+                Some(tag)
+            } else if let Some(numbers) = match elem.to::<FigureElem>() {
+                Some(figure) => figure.sub_ref_numbers(engine)?,
+                None => None,
+            } {
+                Some(numbers)
+            } else {
+                let numbering = self
+                    .numbering_for(styles, &elem, refable)?
+                    .ok_or_else(|| {
+                        eco_format!(
+                            "cannot reference {} without numbering",
+                            elem.func().name()
+                        )
+                    })
+                    .hint(eco_format!(
+                        "you can enable {} numbering with `#set {}(numbering: \"1.\")`",
+                        elem.func().name(),
+                        if elem.func() == EquationElem::elem() {
+                            "math.equation"
+                        } else {
+                            elem.func().name()
+                        }
+                    ))
+                    .at(span)?;
+
+                let state = refable.counter().at(engine, loc)?;
+                let numbers = state.display(engine, &numbering.trimmed())?;
+                Some(match self.to(styles) {
+                    Some(to) => {
+                        match self.range(engine, styles, &elem, state, &numbers, to)? {
+                            Some(range) => {
+                                ranged = true;
+                                range
+                            }
+                            None => numbers,
+                        }
+                    }
+                    None => numbers,
+                })
+            };
 
             let supplement = match self.supplement(styles).as_ref() {
-                Smart::Auto => refable.supplement(),
+                Smart::Auto => match kind_entry(self.supplements(styles), &elem) {
+                    Some(value) => value
+                        .clone()
+                        .cast::<Option<Supplement>>()
+                        .at(span)?
+                        .map(|supplement| {
+                            supplement.resolve(engine, span, [elem.clone()])
+                        })
+                        .transpose()?
+                        .unwrap_or_default(),
+                    None => refable.supplement(),
+                },
                 Smart::Custom(None) => Content::empty(),
-                Smart::Custom(Some(supplement)) => supplement.resolve(engine, [elem])?,
+                Smart::Custom(Some(supplement)) => {
+                    supplement.resolve(engine, span, [elem])?
+                }
             };
 
-            let mut content = numbers;
-            if !supplement.is_empty() {
-                content = supplement + TextElem::packed("\u{a0}") + content;
+            let supplement = match self.supplement_style(styles) {
+                Some(style) if !supplement.is_empty() => {
+                    supplement.styled(TextElem::set_style(style))
+                }
+                _ => supplement,
+            };
+
+            let ibid = self.ibid(styles).filter(|_| self.repeats_previous(engine));
+            let content = if let Some(ibid) = ibid {
+                ibid
+            } else if let Some(format) = self.format(styles) {
+                self.fill(engine, &format, supplement, numbers, loc)?
+            } else {
+                let mut content = match numbers {
+                    Some(numbers) if !supplement.is_empty() => {
+                        let space = if ranged {
+                            SpaceElem::new().pack()
+                        } else {
+                            TextElem::packed('\u{a0}')
+                        };
+                        supplement + space + numbers
+                    }
+                    Some(numbers) => numbers,
+                    None => supplement,
+                };
+
+                if self.show_page(styles) {
+                    content += TextElem::packed(" (") + self.page(engine, styles, loc)?;
+                    content += TextElem::packed(')');
+                }
+
+                content
+            };
+
+            if !self.link(styles) {
+                return Ok(content);
             }
 
-            Ok(content.linked(Destination::Location(loc)))
+            let offset = self.offset(styles).resolve(styles);
+            let dest = if offset.is_zero() {
+                Destination::Location(loc)
+            } else {
+                let mut pos = engine.introspector.position(loc);
+                pos.point.y += offset;
+                Destination::Position(pos)
+            };
+
+            Ok(content.linked_with_alt(dest, self.alt(styles)))
         }))
     }
 }
 
+/// The number of the page at the location, displayed with the page's
+/// numbering or with arabic numerals if the page isn't numbered.
+fn page_number(engine: &mut Engine, loc: Location) -> SourceResult<Content> {
+    let numbering = engine
+        .introspector
+        .page_numbering(loc)
+        .cloned()
+        .unwrap_or_else(|| NumberingPattern::from_str("1").unwrap().into());
+    Counter::new(CounterKey::Page)
+        .at(engine, loc)?
+        .display(engine, &numbering)
+}
+
+/// Look up the entry for a referenced element in a per-kind dictionary. Figures
+/// of a custom kind are looked up by their kind before their element name.
+fn kind_entry<'a>(dict: &'a Dict, elem: &Content) -> Option<&'a Value> {
+    if let Some(figure) = elem.to::<FigureElem>() {
+        if let Smart::Custom(FigureKind::Name(kind)) = figure.kind(StyleChain::default())
+        {
+            if let Ok(value) = dict.get(&kind) {
+                return Some(value);
+            }
+        }
+    }
+
+    dict.get(elem.func().name()).ok()
+}
+
+/// Derive a tooltip like "Section 1: Introduction" from a referenced element.
+fn tooltip(engine: &mut Engine, elem: &Content) -> SourceResult<Option<EcoString>> {
+    let Some(refable) = elem.with::<dyn Refable>() else { return Ok(None) };
+
+    let title = if let Some(heading) = elem.to::<HeadingElem>() {
+        heading.body().plain_text()
+    } else if let Some(figure) = elem.to::<FigureElem>() {
+        match figure.caption(StyleChain::default()) {
+            Some(caption) => caption.body().plain_text(),
+            None => return Ok(None),
+        }
+    } else {
+        return Ok(None);
+    };
+
+    let mut text = refable.supplement().plain_text();
+    if let (Some(numbering), Some(loc)) = (refable.numbering(), elem.location()) {
+        let numbers = refable
+            .counter()
+            .at(engine, loc)?
+            .display(engine, &numbering.trimmed())?;
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(&numbers.plain_text());
+    }
+
+    if !text.is_empty() {
+        text.push_str(": ");
+    }
+    text.push_str(&title);
+    Ok(Some(text))
+}
+
 impl RefElem {
+    /// Whether the reference directly before this one has the same target and
+    /// range end.
+    fn repeats_previous(&self, engine: &mut Engine) -> bool {
+        let Some(loc) = self.location() else { return false };
+        let selector = Self::elem().select();
+        let refs = engine.introspector.query(&selector);
+        let index = engine.introspector.count_before(&selector, loc, false);
+        let Some(prev) = index.checked_sub(1).and_then(|i| refs.get(i)) else {
+            return false;
+        };
+        prev.to::<Self>().is_some_and(|prev| {
+            prev.target() == self.target()
+                && prev.to(StyleChain::default()) == self.to(StyleChain::default())
+        })
+    }
+
+    /// The numbering to display for the referenced element, taking the
+    /// per-kind defaults into account.
+    fn numbering_for(
+        &self,
+        styles: StyleChain,
+        elem: &Content,
+        refable: &dyn Refable,
+    ) -> SourceResult<Option<Numbering>> {
+        match kind_entry(self.numberings(styles), elem) {
+            Some(value) => value.clone().cast::<Numbering>().map(Some).at(self.span()),
+            None => Ok(refable.numbering()),
+        }
+    }
+
+    /// Displays the range from the referenced element, whose counter has the
+    /// given `state` and `numbers`, to the element labelled `to`. Returns
+    /// `None` if both ends have the same number.
+    fn range(
+        &self,
+        engine: &mut Engine,
+        styles: StyleChain,
+        start: &Content,
+        state: CounterState,
+        numbers: &Content,
+        to: Label,
+    ) -> SourceResult<Option<Content>> {
+        let span = self.span();
+        let end = engine.introspector.query_label(to).at(span)?.clone();
+        let start_refable = start.with::<dyn Refable>().unwrap();
+        let Some(refable) = end.with::<dyn Refable>() else {
+            bail!(span, "cannot reference {}", end.func().name());
+        };
+
+        if end.func() != start.func() || refable.counter() != start_refable.counter() {
+            bail!(
+                span,
+                "cannot reference a range from {} to {}",
+                start.func().name(),
+                end.func().name();
+                hint: "both ends of a range must be counted by the same counter",
+            );
+        }
+
+        let Some(numbering) = self.numbering_for(styles, &end, refable)? else {
+            bail!(span, "cannot reference {} without numbering", end.func().name());
+        };
+
+        let end_location = end.location().unwrap();
+        let end_state = refable.counter().at(engine, end_location)?;
+        if end_state == state {
+            return Ok(None);
+        }
+
+        // Compare the positions of both ends in the document instead of their
+        // counter values, which need not increase, e.g. for a counter that
+        // is counted down.
+        let selector = Selector::Elem(end.func(), None);
+        let introspector = &engine.introspector;
+        if introspector.count_before(&selector, end_location, false)
+            < introspector.count_before(&selector, start.location().unwrap(), false)
+        {
+            bail!(
+                span, "reference range ends before it starts";
+                hint: "swap the start and the end of the range",
+            );
+        }
+
+        // Word joiners keep the range from breaking around the separator.
+        let joiner = TextElem::packed('\u{2060}');
+        let end_numbers = end_state.display(engine, &numbering.trimmed())?;
+        Ok(Some(
+            numbers.clone()
+                + joiner.clone()
+                + self.range_separator(styles)
+                + joiner
+                + end_numbers,
+        ))
+    }
+
+    /// Fill the placeholders of a format template with the parts of the
+    /// reference.
+    fn fill(
+        &self,
+        engine: &mut Engine,
+        template: &str,
+        supplement: Content,
+        numbers: Option<Content>,
+        loc: Location,
+    ) -> SourceResult<Content> {
+        let span = self.span();
+        let mut seq = vec![];
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            if start > 0 {
+                seq.push(TextElem::packed(&rest[..start]));
+            }
+
+            let Some(len) = rest[start..].find('}') else {
+                bail!(span, "unclosed placeholder in reference format");
+            };
+
+            seq.push(match &rest[start + 1..start + len] {
+                "supplement" => supplement.clone(),
+                "number" => numbers.clone().unwrap_or_default(),
+                "page" => page_number(engine, loc)?,
+                name => bail!(
+                    span, "unknown placeholder `{{{name}}}` in reference format";
+                    hint: "available placeholders are `{{supplement}}`, `{{number}}`, \
+                           and `{{page}}`",
+                ),
+            });
+            rest = &rest[start + len + 1..];
+        }
+
+        if !rest.is_empty() {
+            seq.push(TextElem::packed(rest));
+        }
+
+        Ok(Content::sequence(seq))
+    }
+
+    /// The page of the referenced element, preceded by the page supplement.
+    fn page(
+        &self,
+        engine: &mut Engine,
+        styles: StyleChain,
+        loc: Location,
+    ) -> SourceResult<Content> {
+        let page = page_number(engine, loc)?;
+        let supplement = self.page_supplement(styles);
+        Ok(if supplement.is_empty() {
+            page
+        } else {
+            supplement + TextElem::packed("\u{a0}") + page
+        })
+    }
+
     /// Turn the reference into a citation.
     pub fn to_citation(
         &self,
@@ -264,18 +840,67 @@ pub enum Supplement {
 
 impl Supplement {
     /// Tries to resolve the supplement into its content.
+    ///
+    /// Calls to user-defined functions are memoized, so resolving the same
+    /// supplement for the same element again, e.g. for many references to one
+    /// figure, reuses the earlier result. The arguments are part of the cache
+    /// key, so functions that depend on fields of the element run again for
+    /// each distinct element.
+    ///
+    /// Functions must return content or a value that displays as text, like a
+    /// string or number. Anything else is reported as an error at `span`.
     pub fn resolve<T: IntoValue>(
         &self,
         engine: &mut Engine,
+        span: Span,
         args: impl IntoIterator<Item = T>,
     ) -> SourceResult<Content> {
         Ok(match self {
             Supplement::Content(content) => content.clone(),
-            Supplement::Func(func) => func.call(engine, args)?.display(),
+            Supplement::Func(func) => match func
+                .call(engine, args)
+                .map_err(|errors| hint_arity(func, errors))?
+            {
+                value @ (Value::None
+                | Value::Int(_)
+                | Value::Float(_)
+                | Value::Str(_)
+                | Value::Symbol(_)
+                | Value::Content(_)) => value.display(),
+                value => bail!(
+                    span,
+                    "expected content, found {}",
+                    value.ty();
+                    hint: "the supplement function must return content or text",
+                ),
+            },
         })
     }
 }
 
+/// Explains errors from calling a supplement function that doesn't take a
+/// single argument. They point at the function itself, without saying how it
+/// was called.
+fn hint_arity(
+    func: &Func,
+    mut errors: EcoVec<SourceDiagnostic>,
+) -> EcoVec<SourceDiagnostic> {
+    // The function's signature tells whether the call failed because of the
+    // arguments, independently of how that failure is worded.
+    if func.accepts_positional(1) != Some(false) {
+        return errors;
+    }
+
+    for error in errors.make_mut() {
+        if error.span == func.span() {
+            error.hint(
+                "supplement functions are called with the element as their only argument",
+            );
+        }
+    }
+    errors
+}
+
 cast! {
     Supplement,
     self => match self {
@@ -297,4 +922,9 @@ pub trait Refable {
 
     /// Returns the numbering of this element.
     fn numbering(&self) -> Option<Numbering>;
+
+    /// A custom tag that references display instead of the element's number.
+    fn tag(&self) -> Option<Content> {
+        None
+    }
 }
@@ -1,22 +1,25 @@
 use std::hash::Hash;
 use std::ops::Range;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use ecow::{eco_format, EcoString, EcoVec};
 use once_cell::sync::Lazy;
 use once_cell::unsync::Lazy as UnsyncLazy;
+use smallvec::smallvec;
 use syntect::highlighting as synt;
 use syntect::parsing::{SyntaxDefinition, SyntaxSet, SyntaxSetBuilder};
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::diag::{At, FileError, SourceResult, StrResult};
+use crate::diag::{bail, At, FileError, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, Args, Array, Bytes, Content, Finalize, Fold, NativeElement,
-    PlainText, Show, Smart, StyleChain, Styles, Synthesize, Value,
+    cast, elem, scope, Args, Array, Bytes, Content, Dict, Finalize, Fold, Label,
+    NativeElement, PlainText, Show, Smart, StyleChain, Styles, Synthesize, Value,
 };
+use crate::introspection::{Count, Counter, CounterState, CounterUpdate};
 use crate::layout::{BlockElem, Em, HAlign};
-use crate::model::Figurable;
+use crate::model::{Figurable, Numbering, NumberingPattern, Refable};
 use crate::syntax::{split_newlines, LinkedNode, Spanned};
 use crate::text::{
     FontFamily, FontList, Hyphenate, Lang, LinebreakElem, LocalName, Region,
@@ -69,6 +72,23 @@ type LineFn<'a> = &'a mut dyn FnMut(i64, Range<usize>, &mut Vec<Content>);
 /// needed, start the text with a single space (which will be trimmed) or use
 /// the single backtick syntax. If your text should start or end with a
 /// backtick, put a space before or after it (it will be trimmed).
+///
+/// # Referencing lines
+/// Individual lines of raw text can be labelled with the `labels` argument,
+/// which maps label names to line numbers. A labelled line can be
+/// [referenced]($ref) and then displays as its line number. The labels belong
+/// to a single raw element, so they cannot be set with a set rule.
+///
+/// ````example
+/// #raw(
+///   "let x = 1;\nx += 1;",
+///   block: true,
+///   lang: "rust",
+///   labels: (incr: 2),
+/// )
+///
+/// The increment is on @incr.
+/// ````
 #[elem(
     scope,
     title = "Raw Text / Code",
@@ -256,6 +276,12 @@ pub struct RawElem {
     #[default(2)]
     pub tab_size: usize,
 
+    /// Labels for individual lines of the raw text, mapping label names to
+    /// line numbers. Internal so that it can't be set for all raw elements at
+    /// once, which would give multiple lines the same label.
+    #[internal]
+    pub labels: Dict,
+
     /// The stylized lines of raw text.
     ///
     /// Made accessible for the [`raw.line` element]($raw.line).
@@ -385,6 +411,15 @@ impl Synthesize for RawElem {
             }));
         };
 
+        for (name, number) in self.labels(styles) {
+            let number = number.cast::<i64>().at(self.span())?;
+            let index = usize::try_from(number - 1).ok();
+            let Some(line) = index.and_then(|i| seq.get_mut(i)) else {
+                bail!(self.span(), "line {number} does not exist");
+            };
+            line.set_label(Label::new(name.as_str()));
+        }
+
         self.push_lines(seq);
 
         Ok(())
@@ -478,7 +513,16 @@ impl PlainText for RawElem {
 /// It allows you to access various properties of the line, such as the line
 /// number, the raw non-highlighted text, the highlighted text, and whether it
 /// is the first or last line of the raw block.
-#[elem(name = "line", title = "Raw Text / Code Line", Show, PlainText)]
+#[elem(
+    name = "line",
+    title = "Raw Text / Code Line",
+    Synthesize,
+    Show,
+    PlainText,
+    Count,
+    Refable,
+    LocalName
+)]
 pub struct RawLine {
     /// The line number of the raw line inside of the raw block, starts at 1.
     #[required]
@@ -495,6 +539,18 @@ pub struct RawLine {
     /// The highlighted raw text.
     #[required]
     pub body: Content,
+
+    /// The line's supplement in references.
+    #[synthesized]
+    #[default(None)]
+    pub supplement: Option<Content>,
+}
+
+impl Synthesize for RawLine {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        self.push_supplement(Some(TextElem::packed(Self::local_name_in(styles))));
+        Ok(())
+    }
 }
 
 impl Show for RawLine {
@@ -510,6 +566,64 @@ impl PlainText for RawLine {
     }
 }
 
+impl Count for RawLine {
+    fn update(&self) -> Option<CounterUpdate> {
+        let number = usize::try_from(*self.number()).unwrap_or_default();
+        Some(CounterUpdate::Set(CounterState(smallvec![number])))
+    }
+}
+
+impl Refable for RawLine {
+    fn supplement(&self) -> Content {
+        // After synthesis, this should always be set.
+        self.supplement().clone().unwrap_or_default()
+    }
+
+    fn counter(&self) -> Counter {
+        Counter::of(Self::elem())
+    }
+
+    fn numbering(&self) -> Option<Numbering> {
+        Some(NumberingPattern::from_str("1").unwrap().into())
+    }
+}
+
+impl LocalName for RawLine {
+    fn local_name(lang: Lang, region: Option<Region>) -> &'static str {
+        match lang {
+            Lang::ALBANIAN => "Rreshti",
+            Lang::ARABIC => "سطر",
+            Lang::BOKMÅL => "Linje",
+            Lang::CHINESE => "行",
+            Lang::CZECH => "Řádek",
+            Lang::DANISH => "Linje",
+            Lang::DUTCH => "Regel",
+            Lang::ESTONIAN => "Rida",
+            Lang::FILIPINO => "Linya",
+            Lang::FINNISH => "Rivi",
+            Lang::FRENCH => "Ligne",
+            Lang::GERMAN => "Zeile",
+            Lang::GREEK => "Γραμμή",
+            Lang::HUNGARIAN => "Sor",
+            Lang::ITALIAN => "Riga",
+            Lang::NYNORSK => "Linje",
+            Lang::POLISH => "Wiersz",
+            Lang::PORTUGUESE => "Linha",
+            Lang::ROMANIAN => "Linia",
+            Lang::RUSSIAN => "Строка",
+            Lang::SERBIAN => "Ред",
+            Lang::SLOVENIAN => "Vrstica",
+            Lang::SPANISH => "Línea",
+            Lang::SWEDISH => "Rad",
+            Lang::TURKISH => "Satır",
+            Lang::UKRAINIAN => "Рядок",
+            Lang::VIETNAMESE => "Dòng",
+            Lang::JAPANESE => "行",
+            Lang::ENGLISH | _ => "Line",
+        }
+    }
+}
+
 /// Wrapper struct for the state required to highlight typst code.
 struct ThemedHighlighter<'a> {
     /// The code being highlighted.
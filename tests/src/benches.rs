@@ -10,6 +10,16 @@ use typst::{Library, World};
 use unscanny::Scanner;
 
 const TEXT: &str = include_str!("../typ/compiler/bench.typ");
+const LOOP: &str = "
+#let values = ()
+#for i in range(10000) { values.push(i) }
+#while values.len() > 0 { let last = values.pop() }
+";
+const REFS: &str = "
+#set heading(numbering: \"1.\")
+#for i in range(1000) [#heading[Section] #label(\"s\" + str(i))]
+#for i in range(1000) [See #ref(label(\"s\" + str(i))).]
+";
 const FONT: &[u8] = include_bytes!("../../assets/fonts/LinLibertine_R.ttf");
 
 main!(
@@ -18,7 +28,9 @@ main!(
     bench_parse,
     bench_edit,
     bench_eval,
+    bench_eval_loop,
     bench_compile,
+    bench_compile_refs,
     bench_render,
 );
 
@@ -65,12 +77,28 @@ fn bench_eval(iai: &mut Iai) {
     });
 }
 
+fn bench_eval_loop(iai: &mut Iai) {
+    let world = BenchWorld::with_text(LOOP);
+    let route = typst::engine::Route::default();
+    let mut tracer = typst::eval::Tracer::new();
+    iai.run(|| {
+        typst::eval::eval(world.track(), route.track(), tracer.track_mut(), &world.source)
+            .unwrap()
+    });
+}
+
 fn bench_compile(iai: &mut Iai) {
     let world = BenchWorld::new();
     let mut tracer = Tracer::new();
     iai.run(|| typst::compile(&world, &mut tracer));
 }
 
+fn bench_compile_refs(iai: &mut Iai) {
+    let world = BenchWorld::with_text(REFS);
+    let mut tracer = Tracer::new();
+    iai.run(|| typst::compile(&world, &mut tracer));
+}
+
 fn bench_render(iai: &mut Iai) {
     let world = BenchWorld::new();
     let mut tracer = Tracer::new();
@@ -87,6 +115,10 @@ struct BenchWorld {
 
 impl BenchWorld {
     fn new() -> Self {
+        Self::with_text(TEXT)
+    }
+
+    fn with_text(text: &str) -> Self {
         let font = Font::new(FONT.into(), 0).unwrap();
         let book = FontBook::from_fonts([&font]);
 
@@ -94,7 +126,7 @@ impl BenchWorld {
             library: Prehashed::new(Library::default()),
             book: Prehashed::new(book),
             font,
-            source: Source::detached(TEXT),
+            source: Source::detached(text),
         }
     }
 
@@ -35,6 +35,11 @@ pub struct TestConfig {
     ///
     /// Defaults to `false`, can be enabled with `Autocomplete: true`.
     pub validate_autocomplete: Option<bool>,
+    /// Loops exceeding the iteration limit stop with a warning instead of
+    /// failing compilation. Only has an effect in the header.
+    ///
+    /// Defaults to `false`, can be enabled with `Lenient loops: true`.
+    pub lenient_loops: Option<bool>,
 }
 
 /// Parsing error when the metadata is invalid.
@@ -157,6 +162,11 @@ pub fn parse_part_metadata(
                     &mut config.validate_autocomplete,
                     &mut invalid_data,
                 ),
+                "Lenient loops" => validate_set_annotation(
+                    value,
+                    &mut config.lenient_loops,
+                    &mut invalid_data,
+                ),
                 annotation_key => {
                     let Ok(kind) = AnnotationKind::from_str(annotation_key) else {
                         continue;
@@ -30,7 +30,7 @@ use oxipng::{InFile, Options, OutFile};
 use rayon::iter::{ParallelBridge, ParallelIterator};
 use tiny_skia as sk;
 use typst::diag::{bail, FileError, FileResult, Severity, SourceDiagnostic, StrResult};
-use typst::eval::Tracer;
+use typst::eval::{LoopLimit, Tracer};
 use typst::foundations::{func, Bytes, Datetime, NoneValue, Repr, Smart, Value};
 use typst::introspection::Meta;
 use typst::layout::{Abs, Frame, FrameItem, Margin, PageElem, Transform};
@@ -193,7 +193,13 @@ fn main() {
     }
 }
 
-fn library() -> Library {
+/// Library options that a test can configure in its header.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+struct LibraryConfig {
+    lenient_loops: bool,
+}
+
+fn library(config: LibraryConfig) -> Library {
     #[func]
     fn test(lhs: Value, rhs: Value) -> StrResult<NoneValue> {
         if lhs != rhs {
@@ -227,7 +233,9 @@ fn library() -> Library {
     // Set page width to 120pt with 10pt margins, so that the inner page is
     // exactly 100pt wide. Page height is unbounded and font size is 10pt so
     // that it multiplies to nice round numbers.
-    let mut lib = Library::default();
+    let loop_limit =
+        if config.lenient_loops { LoopLimit::Warn } else { LoopLimit::Error };
+    let mut lib = Library::builder().with_loop_limit(loop_limit).build();
     lib.styles
         .set(PageElem::set_width(Smart::Custom(Abs::pt(120.0).into())));
     lib.styles.set(PageElem::set_height(Smart::Auto));
@@ -255,6 +263,7 @@ struct TestWorld {
     print: PrintConfig,
     main: FileId,
     library: Prehashed<Library>,
+    library_config: LibraryConfig,
     book: Prehashed<FontBook>,
     fonts: Vec<Font>,
     slots: RwLock<HashMap<FileId, FileSlot>>,
@@ -283,7 +292,8 @@ impl TestWorld {
         Self {
             print,
             main: FileId::new(None, VirtualPath::new("main.typ")),
-            library: Prehashed::new(library()),
+            library: Prehashed::new(library(LibraryConfig::default())),
+            library_config: LibraryConfig::default(),
             book: Prehashed::new(FontBook::from_fonts(&fonts)),
             fonts,
             slots: RwLock::new(HashMap::new()),
@@ -334,6 +344,14 @@ impl World for TestWorld {
 }
 
 impl TestWorld {
+    /// Rebuild the library if the configuration changed.
+    fn configure(&mut self, config: LibraryConfig) {
+        if config != self.library_config {
+            self.library = Prehashed::new(library(config));
+            self.library_config = config;
+        }
+    }
+
     fn set(&mut self, path: &Path, text: String) -> Source {
         self.main = FileId::new(None, VirtualPath::new(path));
         let source = Source::new(self.main, text);
@@ -360,6 +378,7 @@ impl Clone for TestWorld {
             print: self.print,
             main: self.main,
             library: self.library.clone(),
+            library_config: self.library_config,
             book: self.book.clone(),
             fonts: self.fonts.clone(),
             slots: RwLock::new(self.slots.read().unwrap().clone()),
@@ -596,6 +615,9 @@ fn test_part(
     rng: &mut LinearShift,
     verbose: bool,
 ) -> (bool, bool, Vec<Frame>) {
+    world.configure(LibraryConfig {
+        lenient_loops: header_configuration.lenient_loops.unwrap_or(false),
+    });
     let source = world.set(src_path, text);
     if world.print.syntax {
         writeln!(output, "Syntax Tree:\n{:#?}\n", source.root()).unwrap();
@@ -1078,7 +1100,7 @@ fn render_links(canvas: &mut sk::Pixmap, ts: sk::Transform, frame: &Frame) {
                 let ts = ts.pre_concat(to_sk_transform(&group.transform));
                 render_links(canvas, ts, &group.frame);
             }
-            FrameItem::Meta(Meta::Link(_), size) => {
+            FrameItem::Meta(Meta::Link(..), size) => {
                 let w = size.x.to_pt() as f32;
                 let h = size.y.to_pt() as f32;
                 let rect = sk::Rect::from_xywh(0.0, 0.0, w, h).unwrap();
@@ -158,6 +158,36 @@
 // Error: 2-24 array index out of bounds (index: -4, len: 3)
 #(1, 2, 3).slice(0, -4)
 
+---
+// Test the `take` method.
+#test((1, 2, 3, 4).take(2), (1, 2))
+#test((1, 2, 3).take(5), (1, 2, 3))
+#test((1, 2, 3).take(0), ())
+#test(().take(3), ())
+
+#let seen = ()
+#for x in range(10).take(5) { seen.push(x) }
+#test(seen, (0, 1, 2, 3, 4))
+
+---
+// Error: 17-19 number must be at least zero
+#(1, 2, 3).take(-1)
+
+---
+// Test the `windows` method.
+#test((1, 2, 3, 4).windows(2), ((1, 2), (2, 3), (3, 4)))
+#test((1, 2, 3, 4).windows(3), ((1, 2, 3), (2, 3, 4)))
+#test((1, 2).windows(3), ())
+#test(().windows(1), ())
+
+#let diffs = ()
+#for (a, b) in (1, 3, 6, 10).windows(2) { diffs.push(b - a) }
+#test(diffs, (2, 3, 4))
+
+---
+// Error: 20-21 number must be positive
+#(1, 2, 3).windows(0)
+
 ---
 // Test the `position` method.
 #test(("Hi", "❤️", "Love").position(s => s == "❤️"), 1)
@@ -179,6 +209,18 @@
 // Test the `fold` method.
 #test(().fold("hi", grid), "hi")
 #test((1, 2, 3, 4).fold(0, (s, x) => s + x), 10)
+#test(().fold(0, (s, x) => s + x), 0)
+#test((1, 2, 3).fold((), (acc, x) => (x,) + acc), (3, 2, 1))
+#test(("a", "b", "a").fold((:), (acc, x) => {
+  acc.insert(x, acc.at(x, default: 0) + 1)
+  acc
+}), (a: 2, b: 1))
+#test((3, 1, 2).fold(none, (m, x) => if m == none or x > m { x } else { m }), 3)
+
+---
+// Folding stops at the first error.
+// Error: 32-37 cannot add integer and string
+#(1, "a", 2).fold(0, (s, x) => s + x)
 
 ---
 // Error: 20-22 unexpected argument
@@ -293,6 +335,24 @@
 #test(("Hello", "World", "Hi", "There").dedup(key: x => x.len()), ("Hello", "Hi"))
 #test(("Hello", "World", "Hi", "There").dedup(key: x => x.at(0)), ("Hello", "World", "There"))
 
+---
+// Test the `group-by` method.
+#test(().group-by(x => x), (:))
+#test(
+  (1, 2, 3, 4, 5).group-by(x => if calc.even(x) { "even" } else { "odd" }),
+  (odd: (1, 3, 5), even: (2, 4)),
+)
+#test(("b", "a", "b").group-by(x => x).keys(), ("b", "a"))
+
+---
+// Error: 21-27 expected string, found integer
+// Hint: 21-27 the function was called with 1
+#(1, 2, 3).group-by(x => x)
+
+---
+// Error: 27-32 cannot divide by zero
+#(1, 0).group-by(x => str(1 / x))
+
 ---
 // Error: 9-26 unexpected argument: val
 #().zip(val: "applicable")
@@ -348,3 +408,41 @@
 // Keyed pair after this is already identified as an array.
 // Error: 6-14 expected expression, found keyed pair
 #(1, "key": 2)
+
+---
+// Test the `flatten` method.
+#test(((1, 2), (3, 4)).flatten(), (1, 2, 3, 4))
+#test(((1, 2), 3, ((4,), 5)).flatten(), (1, 2, 3, 4, 5))
+#test(((1, 2), (3, (4, 5))).flatten(depth: 1), (1, 2, 3, (4, 5)))
+#test(((1, (2, (3,))),).flatten(depth: 2), (1, 2, (3,)))
+#test(((1, 2),).flatten(depth: 0), ((1, 2),))
+#test(("a", none, (1,)).flatten(), ("a", none, 1))
+
+---
+// Flattened arrays can be iterated directly.
+#let sum = 0
+#for x in ((1, 2), (3, 4)).flatten() { sum += x }
+#test(sum, 10)
+
+---
+// Error: 27-29 number must be at least zero
+#(1, (2,)).flatten(depth: -1)
+
+---
+// Test the `any` and `all` methods.
+#test((1, 2, 3).any(x => x > 2), true)
+#test((1, 2, 3).any(x => x > 3), false)
+#test((1, 2, 3).all(x => x > 0), true)
+#test((1, 2, 3).all(x => x > 1), false)
+#test(().any(x => true), false)
+#test(().all(x => false), true)
+
+---
+// The predicate isn't called after the result is known.
+#test((1, 2, "a").any(x => x + 1 == 3), true)
+#test((1, 2, "a").all(x => x + 1 == 2), false)
+#test((1, 2, 3).any(x => if x == 3 { panic() } else { x == 2 }), true)
+
+---
+// Error: 23-28 cannot add string and integer
+#(1, 2, "a").any(x => x + 1 == 4)
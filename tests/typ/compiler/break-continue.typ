@@ -64,6 +64,24 @@
 
 #test(x, "a_a1a2a_a4")
 
+---
+// Test collecting arrays and dictionaries with break and continue.
+#let evens = for i in range(10) {
+  if calc.odd(i) { continue }
+  if i > 6 { break }
+  (i,)
+}
+
+#test(evens, (0, 2, 4, 6))
+
+#let squares = for i in range(1, 10) {
+  if i == 2 { continue }
+  if i == 4 { break }
+  ((str(i)): i * i)
+}
+
+#test(squares, ("1": 1, "3": 9))
+
 ---
 // Test break outside of loop.
 #let f() = {
@@ -160,3 +178,53 @@
   }
   [#word = #roman \ ]
 }
+
+---
+// Test conditional break and continue.
+#let out = ()
+#for i in range(10) {
+  continue if calc.odd(i)
+  break if i > 6
+  out.push(i)
+}
+
+#test(out, (0, 2, 4, 6))
+
+---
+// Conditional break in a while loop.
+#let i = 0
+#while true {
+  i += 1
+  break if i == 3
+}
+
+#test(i, 3)
+
+---
+// An `if` on the next line is not a condition of `break` or `continue`.
+#let out = ()
+#for i in range(3) {
+  out.push(i)
+  continue
+  if true { out.push("continued") }
+}
+#for i in range(3) {
+  out.push(i)
+  break
+  if true { out.push("broken") }
+}
+
+#test(out, (0, 1, 2, 0))
+
+---
+// A false condition outside of a loop does nothing.
+#let f() = {
+  break if false
+  "done"
+}
+
+#test(f(), "done")
+
+---
+// Error: 27-28 expected boolean, found integer
+#for i in (1,) { break if 1 }
@@ -0,0 +1,25 @@
+// Test the `fixpoint` function.
+// Ref: false
+
+---
+#test(fixpoint("a    b", s => s.replace("  ", " ")), "a b")
+#test(fixpoint(100, n => calc.max(n - 7, 3)), 3)
+#test(fixpoint((3, 1, 2), arr => arr.sorted()), (1, 2, 3))
+#test(fixpoint(1.0, x => calc.round((x + 2 / x) / 2, digits: 8)), 1.41421356)
+
+---
+// A value that is already stable is returned after one step.
+#test(fixpoint(none, x => x), none)
+
+---
+// Error: 2-25 value never reached a fixed point
+// Hint: 2-25 stopped after 10000 iterations
+#fixpoint(0, n => n + 1)
+
+---
+// Error: 2-26 cannot compare function values to find a fixed point
+#fixpoint(x => x, f => f)
+
+---
+// Error: 2-56 cannot compare function values to find a fixed point
+#fixpoint(1, n => if n > 2 { calc.abs } else { n + 1 })
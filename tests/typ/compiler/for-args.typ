@@ -0,0 +1,50 @@
+// Test for loops over arguments.
+// Ref: false
+
+---
+// Positional arguments are visited in order.
+#let collect(..args) = {
+  let out = ()
+  for arg in args {
+    out.push(arg)
+  }
+  out
+}
+
+#test(collect(), ())
+#test(collect(1, "a", 2.5), (1, "a", 2.5))
+
+---
+// Arguments can be destructured like array items.
+#let sum(..args) = {
+  let total = 0
+  for (a, b) in args {
+    total += a * b
+  }
+  total
+}
+
+#test(sum((1, 2), (3, 4)), 14)
+
+---
+// Named arguments are visited through their dictionary.
+#let keys(..args) = {
+  let out = ()
+  for (key, value) in args.named() {
+    out.push(key + "=" + str(value))
+  }
+  for arg in args.pos() {
+    out.push(str(arg))
+  }
+  out
+}
+
+#test(keys(1, a: 2, b: 3), ("a=2", "b=3", "1"))
+
+---
+#let f(..args) = {
+  // Error: 12-16 cannot loop over named arguments
+  // Hint: 12-16 use `args.pos()` or `args.named()` to pick one kind
+  for x in args {}
+}
+#f(1, a: 2)
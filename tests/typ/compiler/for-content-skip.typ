@@ -0,0 +1,23 @@
+// Test iterating over content with whitespace skipping.
+// Ref: false
+
+---
+#let children = for child in [a b].children(skip-space: true) { (child,) }
+#test(children, ([a], [b]))
+
+---
+#let children = for child in [*a* _b_ c].children(skip-space: true) { (child,) }
+#test(children.len(), 3)
+#test(children.first(), strong[a])
+
+---
+// Without skipping, the children are the same as for iterating over the
+// content itself.
+#let body = [a *b* c]
+#test(body.children(), for child in body { (child,) })
+#test(body.children().len(), 5)
+
+---
+// Content that is not a sequence is its only child.
+#test(strong[a].children(), (strong[a],))
+#test([ ].children(skip-space: true), ())
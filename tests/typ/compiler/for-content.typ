@@ -0,0 +1,15 @@
+// Test iterating over content.
+// Ref: false
+
+---
+// Spaces are children, too.
+#let children = for child in [a b] { (child,) }
+#test(children.len(), 3)
+#test(children.first(), [a])
+#test(children.at(1), [ ])
+#test(children.last(), [b])
+
+---
+// Content that is not a sequence is visited as a whole.
+#let children = for child in strong[a] { (child,) }
+#test(children, (strong[a],))
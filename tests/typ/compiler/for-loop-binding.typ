@@ -0,0 +1,97 @@
+// Test the `loop` variable in for loop bodies.
+// Ref: false
+
+---
+// Join items with commas and "and" before the last one.
+#let join(items) = {
+  for item in items {
+    if not loop.first {
+      if loop.last { " and " } else { ", " }
+    }
+    item
+  }
+}
+
+#test(join(("A",)), "A")
+#test(join(("A", "B")), "A and B")
+#test(join(("A", "B", "C")), "A, B and C")
+#test(join(()), none)
+
+---
+// The variable is available for all kinds of iterables.
+#let flags(iter) = {
+  let out = ()
+  for x in iter {
+    out.push((loop.index, loop.first, loop.last))
+  }
+  out
+}
+
+#let expected = ((0, true, false), (1, false, false), (2, false, true))
+#test(flags((1, 2, 3)), expected)
+#test(flags("abc"), expected)
+#test(flags((a: 1, b: 2, c: 3)), expected)
+#test(flags(arguments(1, 2, 3)), expected)
+#test(flags("x"), ((0, true, true),))
+
+---
+// Iterations ending early still see whether they are the last one.
+#let out = ()
+#for x in range(5) {
+  if calc.odd(x) { continue }
+  if loop.last { out.push(x) }
+}
+#test(out, (4,))
+
+---
+// Nested loops have their own variable.
+#let out = ()
+#for x in range(2) {
+  let outer = loop
+  for y in range(3) {
+    out.push((outer.index, loop.index, loop.last))
+  }
+}
+#test(out.len(), 6)
+#test(out.at(2), (0, 2, true))
+#test(out.at(3), (1, 0, false))
+
+---
+// An outer variable named `loop` is shadowed in loop bodies, but not after.
+#let loop = "outer"
+#let out = ()
+#for x in range(2) {
+  out.push(loop.index)
+}
+#test(out, (0, 1))
+#test(loop, "outer")
+
+---
+// Bodies that don't refer to `loop` leave an outer variable of that name
+// alone.
+#let loop = (index: "outer")
+#let get() = loop.index
+#let out = ()
+#for x in range(2) { out.push(get()) }
+#test(out, ("outer", "outer"))
+#test(loop.index, "outer")
+
+---
+// Variables named `loop` that the pattern binds take precedence.
+#let out = ()
+#for loop in range(2) { out.push(loop) }
+#for (loop, x) in ((5, 6),) { out.push(loop) }
+#test(out, (0, 1, 5))
+
+---
+// Variables named `loop` that the pattern assigns to are left alone until
+// the loop ends.
+#let loop = none
+#let out = ()
+#for mut loop in (10, 20) { out.push(loop.index) }
+#test(out, (0, 1))
+#test(loop, 20)
+
+---
+// Error: 2-6 unknown variable: loop
+#loop
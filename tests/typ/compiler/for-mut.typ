@@ -0,0 +1,44 @@
+// Test for loops that assign to existing variables.
+// Ref: false
+
+---
+// The outer variable is updated instead of shadowed.
+#let last = none
+#for mut last in (1, 2, 3) {}
+#test(last, 3)
+
+---
+// Destructuring assigns to each variable.
+#let (a, b) = (0, 0)
+#let sum = 0
+#for mut (a, b) in ((1, 2), (3, 4)) {
+  sum += a + b
+}
+#test((a, b), (3, 4))
+#test(sum, 10)
+
+---
+// Without `mut`, the loop variable shadows the outer one.
+#let x = "outer"
+#for x in (1, 2) {}
+#test(x, "outer")
+
+---
+// `mut` is still a valid variable name.
+#let total = 0
+#for mut in (1, 2, 3) {
+  total += mut
+}
+#test(total, 6)
+
+---
+// Error: 10-11 unknown variable: y
+#for mut y in (1, 2) {}
+
+---
+#let x = 0
+#let f() = {
+  // Error: 11-12 variables from outside the function are read-only and cannot be modified
+  for mut x in (1, 2) {}
+}
+#f()
@@ -129,3 +129,141 @@ A#for "v" thing
 
 // Error: 7 expected keyword `in`
 #for a + b in iter {}
+
+---
+// Dictionary values keep their types when destructured.
+#let dict = (int: 1, str: "one", content: [*one*], array: (1,), none: none)
+#let types = ()
+#for (k, v) in dict {
+  types.push(type(v))
+  test(v, dict.at(k))
+}
+#test(types, (int, str, content, array, type(none)))
+
+---
+// For loops over large collections aren't capped like while loops.
+#let count = 0
+#for i in range(15000) {
+  count += 1
+}
+#test(count, 15000)
+
+---
+// Error: 2:2-2:28 loop seems to be infinite
+#let n = 0
+#while n < 15000 { n += 1 }
+
+---
+// Filtering the iterable visits only the passing items.
+#let visited = ()
+#for n in range(10).filter(calc.even) {
+  visited.push(n)
+}
+#test(visited, (0, 2, 4, 6, 8))
+
+---
+// Error: 25-31 expected boolean, found integer
+// Hint: 25-31 the function was called with 1
+#for n in (1, 2).filter(n => n) {}
+
+---
+// Float ranges don't drift over many iterations.
+#let seen = ()
+#for t in range(0.0, 1.0, step: 0.25) {
+  seen.push(t)
+}
+#test(seen, (0.0, 0.25, 0.5, 0.75))
+#for (i, t) in range(0, 100, step: 0.1).enumerate() {
+  test(t, i * 0.1)
+}
+
+---
+// Groups are visited in the order their keys first appear.
+#let records = (
+  (name: "Ada", team: "core"),
+  (name: "Bob", team: "docs"),
+  (name: "Cy", team: "core"),
+)
+#let seen = ()
+#for (team, members) in records.group-by(r => r.team) {
+  seen.push((team, members.map(r => r.name)))
+}
+#test(seen, (("core", ("Ada", "Cy")), ("docs", ("Bob",))))
+
+---
+// Iterate over a week of dates.
+#let day(d) = datetime(year: 2023, month: 10, day: d)
+#let seen = ()
+#for date in datetime.range(day(2), day(9)) {
+  seen.push(date.display("[weekday repr:short]"))
+}
+#test(seen, ("Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"))
+#test(datetime.range(day(2), day(16), step: duration(weeks: 1)), (day(2), day(9)))
+#test(datetime.range(day(9), day(6), step: duration(days: -1)), (day(9), day(8), day(7)))
+#test(datetime.range(day(9), day(9)), ())
+
+---
+// Month steps clamp to the end of shorter months.
+#let seen = ()
+#for date in datetime.range(
+  datetime(year: 2024, month: 1, day: 31),
+  datetime(year: 2024, month: 6, day: 1),
+  step: (months: 1),
+) {
+  seen.push(date.display())
+}
+#test(seen, ("2024-01-31", "2024-02-29", "2024-03-31", "2024-04-30", "2024-05-31"))
+#test(
+  datetime.range(
+    datetime(year: 2023, month: 12, day: 15, hour: 8, minute: 0, second: 0),
+    datetime(year: 2023, month: 9, day: 1, hour: 0, minute: 0, second: 0),
+    step: (months: -2),
+  ).map(d => d.display()),
+  ("2023-12-15 08:00:00", "2023-10-15 08:00:00"),
+)
+
+---
+// Times stop at midnight instead of wrapping around.
+#let time(h) = datetime(hour: h, minute: 0, second: 0)
+#test(
+  datetime.range(time(20), time(23), step: duration(hours: 2)),
+  (time(20), time(22)),
+)
+
+---
+#let day = datetime(year: 2023, month: 10, day: 2)
+// Error: 16-53 step must be a whole number of days for dates
+#datetime.range(day, day, step: duration(hours: 12))
+
+---
+#let time = datetime(hour: 1, minute: 0, second: 0)
+// Error: 16-47 cannot step a time by months
+#datetime.range(time, time, step: (months: 1))
+
+---
+#let day = datetime(year: 2023, month: 10, day: 2)
+// Error: 16-62 cannot create a range from date to time
+#datetime.range(day, datetime(hour: 1, minute: 0, second: 0))
+
+---
+// Iterate over regex matches and their capture groups.
+#let dates = ()
+#for m in "Due 2023-10-02, paid 2023-11-15.".matches(regex("(\d+)-(\d+)-(\d+)")) {
+  let (year, month, day) = m.captures
+  dates.push((int(year), int(month), int(day)))
+}
+#test(dates, ((2023, 10, 2), (2023, 11, 15)))
+
+// Optional groups that don't participate are none.
+#let units = ()
+#for (text, captures) in "3cm 4 5mm".matches(regex("\d+(cm|mm)?")) {
+  units.push((text, captures.first()))
+}
+#test(units, (("3cm", "cm"), ("4", none), ("5mm", "mm")))
+
+// No matches means no iterations.
+#let count = 0
+#for m in "no digits here".matches(regex("\d")) {
+  count += 1
+}
+#test(count, 0)
@@ -0,0 +1,12 @@
+// Test loops whose bodies only have side effects.
+// Ref: false
+
+---
+#let values = ()
+#let result = for i in range(5000) { values.push(i) }
+#test(result, none)
+#test(values.len(), 5000)
+
+#let result = while values.len() > 0 { let last = values.pop() }
+#test(result, none)
+#test(values, ())
@@ -54,6 +54,17 @@
 // Error: 2-21 string index -1 is not a character boundary
 #"🏳️‍🌈".slice(0, -1)
 
+---
+// Test the `take` method.
+#test("abcdef".take(3), "abc")
+#test("abc".take(10), "abc")
+#test("abc".take(0), "")
+#test("🏳️‍🌈!?".take(2), "🏳️‍🌈!")
+
+#let seen = ()
+#for c in "hello".take(2) { seen.push(c) }
+#test(seen, ("h", "e"))
+
 ---
 // Test the `clusters` and `codepoints` methods.
 #test("abc".clusters(), ("a", "b", "c"))
@@ -0,0 +1,19 @@
+// Test loops that stop at the iteration limit with a warning.
+// Ref: false
+// Lenient loops: true
+
+---
+// Warning: 2:2-2:24 loop seems to be infinite
+// Hint: 2:2-2:24 stopped after 10000 iterations
+#let i = 1
+#while i > 0 { i += 1 }
+#test(i, 10001)
+
+---
+// The output produced so far is kept.
+// Warning: 2:10-2:39 loop seems to be infinite
+// Hint: 2:10-2:39 stopped after 10000 iterations
+#let n = 0
+#let x = while n >= 0 { n += 1; (n,) }
+#test(x.len(), 10000)
+#test(x.last(), 10000)
@@ -37,6 +37,21 @@
 // Error: 8-25 condition is always true
 #while 2 < "hello".len() {}
 
+---
+// Compound conditions are only invariant if all operands are.
+// Ref: false
+#let i = 0
+#while true and i < 5 { i += 1 }
+#test(i, 5)
+
+#let done = false
+#while not done or false { done = true }
+#test(done, true)
+
+---
+// Error: 8-22 condition is always true
+#while true and 1 < 2 {}
+
 ---
 // Error: 2:2-2:24 loop seems to be infinite
 #let i = 1
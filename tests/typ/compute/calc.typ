@@ -266,18 +266,35 @@
 #test(range(5, 2, step: -1), (5, 4, 3))
 #test(range(10, 0, step: -3), (10, 7, 4, 1))
 
+---
+// Test float ranges.
+#test(range(0.0, 1.0, step: 0.25), (0.0, 0.25, 0.5, 0.75))
+#test(range(1, 2.0, step: 0.5), (1.0, 1.5))
+#test(range(2.5), (0.0, 1.0, 2.0))
+#test(range(1.0, 0.0, step: -0.5), (1.0, 0.5))
+#test(range(0.0, 1.0, step: 0.1).len(), 10)
+#test(range(0, 1, step: 0.1).last(), 0.9)
+
 ---
 // Error: 7-9 missing argument: end
 #range()
 
 ---
-// Error: 11-14 expected integer, found float
-#range(1, 2.0)
+// Error: 11-14 expected integer or float, found string
+#range(1, "2")
 
 ---
-// Error: 17-22 expected integer, found string
+// Error: 17-22 expected integer or float, found string
 #range(4, step: "one")
 
 ---
 // Error: 18-19 number must not be zero
 #range(10, step: 0)
+
+---
+// Error: 19-22 number must not be zero
+#range(1.0, step: 0.0)
+
+---
+// Error: 7-20 range must be finite
+#range(0, calc.inf)
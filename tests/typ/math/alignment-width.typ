@@ -0,0 +1,27 @@
+// Test the width of multi-line equations with alignment points.
+// Ref: false
+
+---
+// Lines aligned at the equals signs are as wide as the widest left side plus
+// the widest right side.
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  let approx(a, b) = a - b < 0.01pt and b - a < 0.01pt
+  let aligned = width($ x x x &= 1 \ x &= 1 + 1 + 1 $)
+  test(approx(aligned, width($ x x x = 1 + 1 + 1 $)), true)
+
+  // Without alignment points, the lines are just centered.
+  let centered = width($ x x x = 1 \ x = 1 + 1 + 1 $)
+  test(centered, width($ x = 1 + 1 + 1 $))
+  test(aligned > centered, true)
+})
+
+---
+// Further points add columns, which alternate between right and left
+// alignment.
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  let approx(a, b) = a - b < 0.01pt and b - a < 0.01pt
+  let aligned = width($ x &= 1 + 1 & y &= 2 \ x x x &= 1 & y y &= 2 + 2 $)
+  test(approx(aligned, width($ x x x = 1 + 1 y y = 2 + 2 $)), true)
+})
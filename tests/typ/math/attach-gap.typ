@@ -0,0 +1,18 @@
+// Test the gap between stacked scripts.
+// Ref: false
+
+---
+// Tall scripts on a short base are pushed apart to keep a minimum gap.
+#style(styles => {
+  let script = measure($script(frac(a, b))$, styles)
+  let both = measure($x^(frac(a, b))_(frac(a, b))$, styles)
+  test(both.height > 2 * script.height, true)
+})
+
+---
+// Scripts on both sides are separated independently.
+#style(styles => {
+  let right = measure($x^(frac(a, b))_(frac(a, b))$, styles)
+  let left = measure($attach(x, tl: frac(a, b), bl: frac(a, b))$, styles)
+  test(left.height, right.height)
+})
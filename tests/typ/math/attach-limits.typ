@@ -0,0 +1,54 @@
+// Test limits on big operators.
+// Ref: false
+
+---
+// Stacked lower limits are placed under the operator in display mode.
+#style(styles => {
+  let single = measure($ sum_(i in A) x_i $, styles)
+  let stacked = measure($ sum_(i in A \ j in B) x_i $, styles)
+  test(stacked.height > single.height, true)
+})
+
+---
+// Missing top or bottom limits leave the other side untouched.
+#style(styles => {
+  let bottom = measure($ sum_(i=0) $, styles)
+  let both = measure($ sum_(i=0)^n $, styles)
+  let top = measure($ sum^n $, styles)
+  test(both.height > bottom.height, true)
+  test(both.height > top.height, true)
+})
+
+---
+// Inline, limits become scripts and don't grow the operator as much.
+#style(styles => {
+  let inline = measure($sum_(i=0)^n$, styles)
+  let display = measure($ sum_(i=0)^n $, styles)
+  test(inline.height < display.height, true)
+})
+
+---
+// The `limits` style forces limits in inline math.
+#style(styles => {
+  let default = measure($sum_(i=0)^n$, styles)
+  let forced = measure({
+    set math.attach(limits: true)
+    $sum_(i=0)^n$
+  }, styles)
+  test(forced.height > default.height, true)
+})
+
+---
+// And it forces scripts in display math.
+#style(styles => {
+  let default = measure($ sum_(i=0)^n $, styles)
+  let forced = measure({
+    set math.attach(limits: false)
+    $ sum_(i=0)^n $
+  }, styles)
+  test(forced.height < default.height, true)
+})
+
+---
+// Error: 26-27 expected boolean or auto, found integer
+#set math.attach(limits: 1)
@@ -0,0 +1,31 @@
+// Test scripts on both sides of a base.
+// Ref: false
+
+---
+// Left and right scripts combine into one wider fragment.
+#style(styles => {
+  let base = measure($T$, styles)
+  let pre = measure($attach(T, tl: i)$, styles)
+  let post = measure($T_j$, styles)
+  let both = measure($attach(T, tl: i, br: j)$, styles)
+  test(pre.width > base.width, true)
+  test(both.width > pre.width, true)
+  test(both.width > post.width, true)
+  test(both.height >= calc.max(pre.height, post.height), true)
+})
+
+---
+// A left superscript is raised like a right one.
+#style(styles => {
+  let pre = measure($attach(T, tl: i)$, styles)
+  let post = measure($T^i$, styles)
+  test(pre.height, post.height)
+})
+
+---
+// Mixed sides keep the vertical extent of their scripts.
+#style(styles => {
+  let mixed = measure($attach(T, tl: i, br: j)$, styles)
+  let right = measure($T^i_j$, styles)
+  test(mixed.height, right.height)
+})
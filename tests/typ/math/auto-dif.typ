@@ -0,0 +1,31 @@
+// Test automatic spacing before differentials.
+// Ref: false
+
+---
+#let on(body) = {
+  set math.equation(auto-dif: true)
+  body
+}
+
+#style(styles => {
+  let width(it) = measure(it, styles).width
+
+  // Disabled by default.
+  test(width($f(x) d x$) < width($f(x) thin d x$), true)
+
+  // Spaced like a thin space when enabled.
+  test(width(on($f(x) d x$)), width($f(x) thin d x$))
+  test(width(on($f(x) d theta$)), width($f(x) thin d theta$))
+  test(width(on($integral_0^1 f(x) d x$)), width($integral_0^1 f(x) thin d x$))
+
+  // Only in front of a single letter and if something other than an opening
+  // delimiter precedes.
+  test(width(on($f(x) d 2$)), width($f(x) d 2$))
+  test(width(on($f(x) d "xy"$)), width($f(x) d "xy"$))
+  test(width(on($d x$)), width($d x$))
+  test(width(on($(d x)$)), width($(d x)$))
+  test(width(on($f(x) (d x)$)), width($f(x) (d x)$))
+
+  // Explicit differentials aren't spaced twice.
+  test(width(on($f(x) dif x$)), width($f(x) dif x$))
+})
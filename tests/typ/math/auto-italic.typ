@@ -0,0 +1,39 @@
+// Test disabling automatic italics.
+// Ref: false
+
+---
+#style(styles => {
+  let default = measure($x$, styles)
+  let upright = measure($upright(x)$, styles)
+  let plain = measure({
+    set math.equation(auto-italic: false)
+    $x$
+  }, styles)
+  let forced = measure({
+    set math.equation(auto-italic: false)
+    $italic(x)$
+  }, styles)
+  test(default == upright, false)
+  test(plain, upright)
+  test(forced, default)
+})
+
+---
+// Nested equations keep the italic mode of their surroundings.
+#style(styles => {
+  let default = measure($x$, styles)
+  let upright = measure($upright(x)$, styles)
+  test(measure($sans(#box($x$))$, styles), default)
+  test(measure($upright(#box($x$))$, styles), upright)
+  test(measure($sans(upright(#box($x$)))$, styles), upright)
+  test(measure($upright(sans(#box($x$)))$, styles), upright)
+  test(measure($upright(italic(#box($x$)))$, styles), default)
+})
+
+---
+// Single letters are still italicized inside a variant change.
+#style(styles => {
+  let sans = measure($sans(x)$, styles)
+  test(sans == measure($sans(upright(x))$, styles), false)
+  test(measure($bb(sans(x))$, styles), measure($sans(x)$, styles))
+})
@@ -0,0 +1,45 @@
+// Test framed math.
+// Ref: false
+
+---
+// The body stays on the baseline: next to unframed text with the same
+// descender, the frame is as tall as the whole row.
+#style(styles => {
+  let size(it) = measure(it, styles)
+  let row = size($ y boxed(y, inset: #2pt) y $)
+  test(row.height, size($ boxed(y, inset: #2pt) $).height)
+})
+
+---
+// Framed content is spaced like an ordinary symbol.
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  let plus = width($ a boxed(+, inset: #0pt) b $)
+  test(plus, width($ a class("normal", +) b $))
+  test(plus < width($ a + b $), true)
+})
+
+---
+// The frame adds its inset on every side.
+#style(styles => {
+  let size(it) = measure(it, styles)
+  let body = size($ x + y $)
+  let boxed = size($ boxed(x + y, inset: #3pt) $)
+  test(boxed.width, body.width + 6pt)
+  test(boxed.height, body.height + 6pt)
+  test(size($ boxed(x + y, inset: #0pt) $), body)
+})
+
+---
+// The stroke doesn't change the size.
+#style(styles => {
+  let size(it) = measure(it, styles)
+  test(
+    size($ boxed(x, stroke: #(paint: blue, thickness: 2pt, dash: "dashed")) $),
+    size($ boxed(x) $),
+  )
+})
+
+---
+// Error: 20-23 expected length, found string
+$ boxed(x, inset: #"a") $
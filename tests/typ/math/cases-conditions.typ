@@ -0,0 +1,34 @@
+// Test case distinctions with conditions.
+// Ref: false
+
+---
+// A two-case piecewise function has the same height as one without columns.
+#style(styles => {
+  let columns = measure($cases(x, "if" x >= 0; -x, "otherwise")$, styles)
+  let inline = measure($cases(x "if" x >= 0, -x "otherwise")$, styles)
+  let delta = columns.height - inline.height
+  test(delta < 0.01pt and delta > -0.01pt, true)
+  test(columns.width > inline.width, true)
+})
+
+---
+// Conditions are left-aligned in their own column, after the widest value.
+#style(styles => {
+  let close(a, b) = a - b < 0.01pt and b - a < 0.01pt
+  let wide = measure($cases(100, "if" y)$, styles)
+  let both = measure($cases(x, "if" y; 100, "if" y)$, styles)
+  let missing = measure($cases(100; x, "if" y)$, styles)
+  test(close(both.width, wide.width), true)
+  test(close(missing.width, wide.width), true)
+  test(both.height > wide.height, true)
+})
+
+---
+// Without conditions, nothing changes.
+#style(styles => {
+  test(measure($cases(1; 2)$, styles), measure($cases(1, 2)$, styles))
+})
+
+---
+// Error: 14-21 expected a value and an optional condition
+$ cases(1, 2;3, 4, 5) $
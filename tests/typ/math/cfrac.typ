@@ -0,0 +1,32 @@
+// Test continued fractions.
+// Ref: false
+
+---
+// All levels keep the size of the outermost one, unlike nested fractions.
+#style(styles => {
+  let cfrac = measure($ cfrac(1, x + cfrac(1, x + cfrac(1, x))) $, styles)
+  let frac = measure($ frac(1, x + frac(1, x + frac(1, x))) $, styles)
+  let row = measure($ x + x + x $, styles)
+  test(cfrac.width > frac.width, true)
+  test(cfrac.height > frac.height, true)
+  test(cfrac.width > row.width, true)
+})
+
+---
+// A single level matches a fraction in a block equation.
+#style(styles => {
+  let cfrac = measure($ cfrac(a, b) $, styles)
+  let frac = measure($ frac(a, b) $, styles)
+  test(cfrac.width, frac.width)
+})
+
+---
+// The bar follows the fraction styles.
+#style(styles => {
+  let normal = measure($ cfrac(1, 2) $, styles)
+  let thick = measure({
+    set math.frac(thickness: 5pt)
+    $ cfrac(1, 2) $
+  }, styles)
+  test(thick.height > normal.height, true)
+})
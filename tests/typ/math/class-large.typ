@@ -0,0 +1,12 @@
+// Test content of the large class.
+// Ref: false
+
+---
+// Arbitrary content of the large class grows in display style only.
+#let square = box(width: 2pt, height: 2pt, fill: black)
+#let op = math.class("large", square)
+#style(styles => {
+  test(measure($ op $, styles).height > measure($ square $, styles).height, true)
+  test(measure($op$, styles).height, measure($square$, styles).height)
+})
+
@@ -0,0 +1,14 @@
+// Test sizing of boxes embedded in math.
+// Ref: false
+
+---
+#style(styles => {
+  let size = measure($box(width: 2cm, height: 1cm)[a]$, styles)
+  test(size.width, 2cm)
+})
+
+---
+#style(styles => {
+  let boxed = measure($box(width: 2cm, align(right)[x])$, styles)
+  test(boxed.width, 2cm)
+})
@@ -0,0 +1,14 @@
+// Test which parts of an equation are cramped.
+// Ref: false
+
+---
+// The base of a superscript is not cramped, but a denominator is.
+#style(styles => {
+  let height(it) = measure(it, styles).height
+  test(height($x^2$), height($inline(x^2)$))
+  test(height($x^2$) > height($inline(x^2, cramped: #true)$), true)
+
+  let frac = height($display(1/(x^2))$)
+  test(frac, height($display(1/inline(x^2, cramped: #true))$))
+  test(frac < height($display(1/inline(x^2))$), true)
+})
@@ -0,0 +1,23 @@
+// Test that vertical bars stretch like other delimiters.
+// Ref: false
+
+---
+// The body sits mostly below the axis, so bars that are stretched around the
+// axis to cover it grow above it, too.
+#let body = $x/(y/(z/w))$
+#style(styles => {
+  let plain = measure($display(body)$, styles)
+  let fixed = measure($display(abs(body, size: #0%))$, styles)
+  test(fixed.height, plain.height)
+  for bars in ($display(abs(body))$, $display(norm(body))$) {
+    test(measure(bars, styles).height > plain.height, true)
+  }
+})
+
+---
+// Double bars are wider than single ones at any height.
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  test(width($norm(x)$) > width($abs(x)$), true)
+  test(width($display(norm(x/(y/z)))$) > width($display(abs(x/(y/z)))$), true)
+})
@@ -0,0 +1,30 @@
+// Test the height of stretched delimiters.
+// Ref: false
+
+---
+// With optical delimiters, delimiters around content that is lower than the
+// font's minimum height for delimited sub-formulas keep their natural size,
+// even if the content is a bit higher than them.
+#set math.equation(optical-delims: true)
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  let strut(height) = box(height: height)
+
+  test(width($lr((#strut(0.85em)))$), width($\(#strut(0.85em)\)$))
+
+  // Higher content gets larger variants, which are also wider.
+  test(width($lr((#strut(1.5em)))$) > width($\(#strut(1.5em)\)$), true)
+  test(width($lr((#strut(3em)))$) > width($lr((#strut(1.5em)))$), true)
+})
+
+---
+// Test that delimiters are assembled along the vertical axis when no size
+// variant is tall enough.
+#style(styles => {
+  let height(it) = measure(it, styles).height
+  let target = height(box(height: 8em))
+  for delim in ("|", "‖", "{", "⟨", "⌊") {
+    let eq = $lr(#delim, size: #8em)$
+    test(height(eq) >= 0.9 * target, true)
+  }
+})
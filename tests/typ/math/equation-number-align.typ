@@ -0,0 +1,27 @@
+// Test the placement of equation numbers.
+// Ref: false
+
+---
+// References work the same with the number on either side.
+#set math.equation(numbering: "(1)", number-align: left)
+$ a + b = c $ <left>
+#set math.equation(number-align: end)
+$ c = a + b $ <end>
+
+#locate(loc => {
+  let counter = counter(math.equation)
+  test(counter.at(query(<left>, loc).first().location()), (1,))
+  test(counter.at(query(<end>, loc).first().location()), (2,))
+})
+
+---
+// The number doesn't overlap the equation if both are on the same side.
+#set math.equation(numbering: "(1)", number-align: start)
+#set align(left)
+$ x $
+
+---
+// Error: 2:1-2:6 equation numbers cannot be centered
+// Hint: 2:1-2:6 use `start`, `end`, `left`, or `right` instead
+#set math.equation(numbering: "(1)", number-align: center)
+$ x $
@@ -0,0 +1,11 @@
+// Test that layout errors in math point to the failing element.
+// Ref: false
+
+---
+// Error: 1:20-1:58 expected angle, found string
+#let slash(body) = math.cancel(body, angle: a => "steep")
+$ a + slash(b) $
+
+---
+// Error: 8-37 expected angle, found integer
+$ a + #math.cancel(b, angle: a => 1) $
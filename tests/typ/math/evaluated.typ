@@ -0,0 +1,23 @@
+// Test evaluation bars.
+// Ref: false
+
+---
+// The bar grows with the body and the limits are attached to it.
+#style(styles => {
+  let size(it) = measure(it, styles)
+  let body = size($display(x^2/2)$)
+  let bare = size($display(evaluated(x^2/2))$)
+  let limits = size($display(evaluated(x^2/2, bottom: 0, top: 1))$)
+  test(bare.width > body.width, true)
+  test(bare.height >= body.height, true)
+  test(limits.width > bare.width, true)
+  test(limits.height >= bare.height, true)
+})
+
+---
+// Either limit can be given on its own.
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  test(width($evaluated(F(x), top: b)$) > width($evaluated(F(x))$), true)
+  test(width($evaluated(F(x), bottom: a)$) > width($evaluated(F(x))$), true)
+})
@@ -0,0 +1,19 @@
+// Test dashed and dotted fraction bars.
+// Ref: false
+
+---
+// Dash patterns keep the metrics of a solid bar of the same thickness, also
+// in nested fractions.
+#style(styles => {
+  let size(dash) = measure({
+    set math.frac(dash: dash, thickness: 1pt)
+    $ a/b quad 1/(1 + 1/x) $
+  }, styles)
+  test(size("dotted"), size(none))
+  test(size("dashed"), size(none))
+})
+
+---
+// The dash pattern is resolved from its name.
+#test(math.frac([a], [b], dash: "dashed").dash, (array: (3pt, 3pt), phase: 0pt))
+#test(math.frac([a], [b]).has("dash"), false)
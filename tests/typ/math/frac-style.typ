@@ -0,0 +1,69 @@
+// Test fraction bar thickness, gap, dash, and paint overrides.
+// Ref: false
+
+---
+#style(styles => {
+  let normal = measure($ a/b $, styles)
+  let thick = measure({
+    set math.frac(thickness: 5pt)
+    $ a/b $
+  }, styles)
+  test(thick.height > normal.height, true)
+})
+
+---
+#style(styles => {
+  let normal = measure($ a/b $, styles)
+  let spaced = measure({
+    set math.frac(num-gap: 10pt, denom-gap: 10pt)
+    $ a/b $
+  }, styles)
+  test(spaced.height > normal.height + 10pt, true)
+  test(spaced.width, normal.width)
+})
+
+---
+// Binomials are unaffected.
+#style(styles => {
+  let normal = measure($ binom(a, b) $, styles)
+  let thick = measure({
+    set math.frac(thickness: 5pt)
+    $ binom(a, b) $
+  }, styles)
+  test(thick.height, normal.height)
+})
+
+---
+// Dashed and dotted bars keep the fraction's metrics.
+#style(styles => {
+  let normal = measure($ a/b $, styles)
+  for dash in ("dashed", "dotted", (3pt, 1pt)) {
+    let dashed = measure({
+      set math.frac(dash: dash)
+      $ a/b $
+    }, styles)
+    test(dashed, normal)
+  }
+})
+
+---
+// Bar colors keep the fraction's metrics, also for continued fractions.
+#style(styles => {
+  let normal = measure($ a/b $, styles)
+  let colored = measure({
+    set math.frac(bar-fill: blue)
+    $ a/b $
+  }, styles)
+  test(colored, normal)
+
+  let normal = measure($ cfrac(a, b) $, styles)
+  let colored = measure({
+    set math.frac(bar-fill: blue)
+    $ cfrac(a, b) $
+  }, styles)
+  test(colored, normal)
+})
+
+---
+// Error: 26-29 expected color, gradient, pattern, or none, found length
+#set math.frac(bar-fill: 1pt)
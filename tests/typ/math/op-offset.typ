@@ -0,0 +1,29 @@
+// Test the axis offset of large operators.
+// Ref: false
+
+---
+// A positive offset raises the operator: it grows the line's ascent, while
+// lowering it keeps it within the descent of the hanging box.
+#let para(offset) = {
+  set math.equation(op-offset: offset)
+  [x $sum$ #box(width: 1pt, height: 3em, baseline: 100%)]
+}
+
+#style(styles => {
+  let normal = measure(para(0pt), styles)
+  let raised = measure(para(1em), styles)
+  let lowered = measure(para(-1em), styles)
+  test(raised.height > normal.height, true)
+  test(lowered.height <= normal.height, true)
+})
+
+---
+// Only large operators are affected.
+#style(styles => {
+  let normal = measure($a + b$, styles)
+  let offset = measure({
+    set math.equation(op-offset: 1em)
+    $a + b$
+  }, styles)
+  test(offset, normal)
+})
@@ -0,0 +1,32 @@
+// Test content set over and under a base.
+// Ref: false
+
+---
+// The annotation is stacked over or under the base in script size.
+#style(styles => {
+  let size(it) = measure(it, styles)
+  let eq = size($=$)
+  let def = size($script("def")$)
+  let over = size($overset("def", =)$)
+  let under = size($underset("def", =)$)
+  test(over.width, calc.max(eq.width, def.width))
+  test(over.height > eq.height + def.height, true)
+  test(under.width, over.width)
+  test(under.height > eq.height + def.height, true)
+})
+
+---
+// The wider one of base and annotation determines the width.
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  test(width($overset("definitely", =)$), width($script("definitely")$))
+  test(width($overset(a, arrow.long.double)$), width($arrow.long.double$))
+})
+
+---
+// The result is spaced like the base.
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  let parts = width($a$) + width($overset(b, =)$) + width($c$)
+  test(width($a overset(b, =) c$) > parts, true)
+})
@@ -0,0 +1,27 @@
+// Test phantoms and smashed content.
+// Ref: false
+
+---
+#style(styles => {
+  let size(it) = measure(it, styles)
+
+  // A phantom takes up the full size of its body.
+  test(size($ phantom(x/y) $), size($ x/y $))
+
+  // Turning off a dimension gives horizontal and vertical phantoms.
+  test(size($ phantom(x/y, vertical: false) $).width, size($ x/y $).width)
+  test(size($ phantom(x/y, vertical: false) $).height, 0pt)
+  test(size($ phantom(x/y, horizontal: false) $).width, 0pt)
+  test(size($ phantom(x/y, horizontal: false) $).height, size($ x/y $).height)
+
+  // Smashed content keeps its width, but doesn't add to the height.
+  test(size($ smash(x/y) $).width, size($ x/y $).width)
+  test(size($ a smash(x/y) $).height, size($ a $).height)
+})
+
+---
+// Phantoms are spaced like their body.
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  test(width($ a phantom(+) b $), width($ a + b $))
+})
@@ -0,0 +1,13 @@
+// Test the width of spaces in math.
+// Ref: false
+
+---
+// Spaces next to text take the advance of the math font's space glyph.
+#style(styles => {
+  for font in ("New Computer Modern Math", "Fira Math") {
+    let width(it) = measure(text(font: font, it), styles).width
+    let math = width($"xx" "xx"$) - width($"xx""xx"$)
+    let plain = width[xx xx] - width[xxxx]
+    test(calc.abs((math - plain) / 1pt) < 0.01, true)
+  }
+})
@@ -0,0 +1,37 @@
+// Test stylistic sets for parts of an equation.
+// Ref: false
+
+---
+// The first stylistic set of the default math font has alternative script
+// letters with different widths.
+#style(styles => {
+  let plain = measure($cal(H)$, styles)
+  let styled = measure($#math.stylistic-set(1)[$cal(H)$]$, styles)
+  test(plain.width == styled.width, false)
+})
+
+---
+// The stylistic set does not leak out of its scope.
+#style(styles => {
+  let plain = measure($cal(H)$, styles)
+  let single = measure($#math.stylistic-set(1)[$cal(H)$]$, styles)
+  let both = measure($#math.stylistic-set(1)[$cal(H)$]cal(H)$, styles)
+  test(both.width, single.width + plain.width)
+})
+
+---
+// Error: 23-25 stylistic set must be between 1 and 20
+$ #math.stylistic-set(21)[$x$] $
+
+---
+// Substitutions are skipped inside of `unsubstituted`, but not around it.
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  let plain = width($cal(H)$)
+  let styled = width($#math.stylistic-set(1)[$cal(H)$]$)
+  test(width($#math.stylistic-set(1)[$unsubstituted(cal(H))$]$), plain)
+  test(
+    width($#math.stylistic-set(1)[$unsubstituted(cal(H)) cal(H)$]$),
+    plain + styled,
+  )
+})
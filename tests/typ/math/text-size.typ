@@ -0,0 +1,21 @@
+// Test the size of multi-letter text in scripts.
+// Ref: false
+
+---
+// Text is scaled by the font's script size factors.
+#style(styles => {
+  let normal = measure($"abc"$, styles)
+  let script = measure($script("abc")$, styles)
+  let sscript = measure($sscript("abc")$, styles)
+  test(calc.abs(script.width / normal.width - 0.7) < 0.01, true)
+  test(calc.abs(sscript.width / normal.width - 0.5) < 0.01, true)
+})
+
+---
+// A multi-letter operator in a subscript shrinks like a single letter.
+#style(styles => {
+  let base = measure($x$, styles)
+  let op = measure($log$, styles)
+  let attached = measure($x_log$, styles)
+  test(attached.width < base.width + op.width, true)
+})
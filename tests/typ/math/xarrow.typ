@@ -0,0 +1,56 @@
+// Test labelled arrows.
+// Ref: false
+
+---
+// Without labels, the arrow keeps its natural size.
+#style(styles => {
+  let plain = measure($a -> b$, styles)
+  let arrow = measure($a xarrow(->) b$, styles)
+  test(arrow.width, plain.width)
+})
+
+---
+// A wide label stretches the arrow.
+#style(styles => {
+  let label = measure($script("a rather long label")$, styles)
+  let mapsto = measure($xarrow(|->, over: "a rather long label")$, styles)
+  let plain = measure($|->$, styles)
+  test(mapsto.width > label.width, true)
+  test(mapsto.height > plain.height, true)
+})
+
+---
+// Labels can go above and below.
+#style(styles => {
+  let over = measure($xarrow(->, over: f)$, styles)
+  let both = measure($xarrow(->, over: f, under: g)$, styles)
+  test(both.height > over.height, true)
+})
+
+---
+// Equals signs and harpoons stretch under wide labels, too.
+#style(styles => {
+  let label = measure($script("a rather long label")$, styles)
+  let plain = measure($=$, styles)
+  let equals = measure($xarrow(=, over: "a rather long label")$, styles)
+  let harpoon = measure($xarrow(harpoon.rt, under: "a rather long label")$, styles)
+  test(equals.width > label.width, true)
+  test(harpoon.width > label.width, true)
+  test(equals.height > plain.height, true)
+})
+
+---
+// The minimum width applies with and without labels.
+#style(styles => {
+  let plain = measure($a = b$, styles)
+  let wide = measure($a xarrow(=, min-width: #4em) b$, styles)
+  test(wide.width > plain.width, true)
+  let short = measure($xarrow(=, over: f, min-width: #40pt)$, styles)
+  test(short.width > 39pt, true)
+  let long = measure($xarrow(=, over: "a rather long label", min-width: #1pt)$, styles)
+  test(long, measure($xarrow(=, over: "a rather long label")$, styles))
+})
+
+---
+// Error: 9-10 expected an arrow or another relation symbol
+$xarrow(+)$
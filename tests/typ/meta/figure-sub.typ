@@ -0,0 +1,43 @@
+// Test sub-figures and references to them.
+// Ref: false
+
+---
+#let composite(..figures) = {
+  set figure(sub: true, numbering: "(a)")
+  grid(columns: figures.pos().len(), gutter: 1em, ..figures.pos())
+}
+
+#figure([Single], caption: [A single figure]) <single>
+
+#figure(
+  composite(
+    [#figure([Left], caption: [Left]) <left>],
+    [#figure([Right], caption: [Right]) <right>],
+  ),
+  caption: [Both sides],
+) <both>
+
+#figure(
+  composite(
+    [#figure([Top], caption: [Top]) <top>],
+    [#figure([Middle], caption: [Middle]) <middle>],
+    [#figure([Bottom], caption: [Bottom]) <bottom>],
+  ),
+  caption: [All levels],
+) <levels>
+
+#locate(loc => {
+  // Sub-figures don't step the counter of their kind.
+  let figures = counter(figure.where(kind: image))
+  test(figures.at(query(<both>, loc).first().location()), (2,))
+  test(figures.at(query(<levels>, loc).first().location()), (3,))
+  test(figures.final(loc), (3,))
+})
+
+@single, @both, @left, @right, @levels, @top, @middle, and @bottom.
+
+---
+// Error: 2:5-2:10 cannot reference figure without numbering
+// Hint: 2:5-2:10 you can enable figure numbering with `#set figure(numbering: "1.")`
+#figure([#figure([Part], sub: true, numbering: none) <part>], caption: [Whole])
+See @part.
@@ -0,0 +1,29 @@
+// Test that query results are in document order.
+// Ref: false
+
+---
+#metadata("first") <b>
+#metadata("second") <c>
+#metadata("third") <a>
+
+#locate(loc => {
+  let values = ()
+  for elem in query(selector(<c>).or(<a>).or(<b>), loc) {
+    values.push(elem.value)
+  }
+  test(values, ("first", "second", "third"))
+})
+
+---
+#set heading(numbering: "1.")
+= Beta <beta>
+= Alpha <alpha>
+== Gamma <gamma>
+
+#locate(loc => {
+  let bodies = ()
+  for elem in query(heading.where(level: 2).or(<alpha>).or(<beta>), loc) {
+    bodies.push(elem.body)
+  }
+  test(bodies, ([Beta], [Alpha], [Gamma]))
+})
@@ -0,0 +1,23 @@
+// Test alternative link descriptions for references.
+// Ref: false
+
+---
+#set heading(numbering: "1.")
+#show ref: it => {
+  test(it.alt, "Jump to the introduction")
+  it
+}
+
+= Introduction <intro>
+#ref(<intro>, alt: "Jump to the introduction")
+
+---
+#set heading(numbering: "1.")
+#set ref(alt: "Go to the section")
+#show ref: it => {
+  test(it.alt, "Go to the section")
+  it
+}
+
+= Introduction <intro>
+@intro
@@ -0,0 +1,40 @@
+// Test the counter snapshot of references.
+// Ref: false
+
+---
+// Errors in `locate` are only reported for the final layout pass, where the
+// element must be known.
+#set heading(numbering: "1.a")
+#show ref: it => locate(_ => {
+  test(it.counter-at, (1, 2))
+  it
+})
+
+= Introduction
+== First
+== Second <second>
+See @second.
+
+---
+// The snapshot matches a manual lookup, also for references to elements
+// defined later.
+#set math.equation(numbering: "(1)")
+#show ref: it => locate(_ => {
+  test(it.counter-at, counter(math.equation).at(it.element.location()))
+  test(it.counter-at, (2,))
+  it
+})
+
+See @later.
+$ a $
+$ b $ <later>
+
+---
+// Citations have no snapshot.
+#show ref: it => {
+  test(it.counter-at, none)
+  it
+}
+
+@arrgh
+#bibliography("/files/works.bib")
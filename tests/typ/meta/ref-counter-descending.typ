@@ -0,0 +1,24 @@
+// Test references to elements whose counter counts down.
+// Ref: false
+
+---
+#let steps = counter(figure.where(kind: "step"))
+#let step(body) = {
+  steps.update(n => n - 2)
+  figure(kind: "step", supplement: [Step], body)
+}
+
+#show ref: it => locate(loc => {
+  let numbers = it.element.counter.at(it.element.location())
+  let expected = (a: (3,), b: (2,), c: (1,)).at(str(it.target))
+  test(numbers, expected)
+  it
+})
+
+// Each step first counts down by two and then the figure steps up by one.
+#steps.update(4)
+#step[Gather materials] <a>
+#step[Assemble] <b>
+#step[Test] <c>
+
+Remaining: @a, @b, and @c.
@@ -0,0 +1,25 @@
+// Test that references discover their element.
+// Ref: false
+
+---
+// Errors in `locate` are only reported for the final layout pass, where the
+// element must be known.
+#set heading(numbering: "1.")
+#show ref: it => locate(_ => {
+  test(it.element.func(), heading)
+  test(it.element.body, [Introduction])
+  it
+})
+
+= Introduction <intro>
+See @intro.
+
+---
+#set heading(numbering: "1.")
+#show ref: it => locate(_ => {
+  test(it.element.func(), heading)
+  it
+})
+
+Defined later: @later.
+= Later <later>
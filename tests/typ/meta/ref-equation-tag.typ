@@ -0,0 +1,26 @@
+// Test references to equations with a custom tag.
+// Ref: false
+
+---
+#show ref: it => locate(_ => {
+  test(it.element.func(), math.equation)
+  test(it.element.tag, [($star$)])
+  it
+})
+
+#math.equation(block: true, tag: [($star$)], $ a + b = c $) <star>
+By @star, we are done.
+
+---
+// Tagged equations don't need a numbering and don't step the counter.
+#set math.equation(numbering: "(1)")
+$ x = 1 $ <one>
+#math.equation(block: true, tag: [($star$)], $ y = 2 $) <star>
+$ z = 3 $ <two>
+
+#locate(loc => {
+  test(counter(math.equation).at(query(<star>, loc).first().location()), (1,))
+  test(counter(math.equation).at(query(<two>, loc).first().location()), (2,))
+})
+
+See @one, @star, and @two.
@@ -0,0 +1,27 @@
+// Test reference format templates.
+// Ref: false
+
+---
+#set page(height: 80pt, numbering: "1")
+#set heading(numbering: "1.")
+
+= Introduction <intro>
+#pagebreak()
+See #ref(<intro>, format: "{supplement} {number} on page {page}").
+
+// The template takes precedence over the page option.
+#set ref(show-page: true, format: "{number}")
+See @intro.
+
+---
+#set heading(numbering: "1.")
+// Error: 2:2-2:33 unknown placeholder `{title}` in reference format
+// Hint: 2:2-2:33 available placeholders are `{supplement}`, `{number}`, and `{page}`
+= Intro <intro>
+#ref(<intro>, format: "{title}")
+
+---
+#set heading(numbering: "1.")
+// Error: 2:2-2:33 unclosed placeholder in reference format
+= Intro <intro>
+#ref(<intro>, format: "{number")
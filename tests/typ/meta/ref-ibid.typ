@@ -0,0 +1,50 @@
+// Test repeated references with ibid.
+// Ref: false
+
+---
+// The second of two consecutive references is replaced, the first isn't.
+#set heading(numbering: "1.")
+#set ref(ibid: [ibid.])
+
+= Introduction <intro>
+= Results <results>
+
+@intro and @intro, @results, @intro, @intro.
+
+#locate(loc => style(styles => {
+  let width(it) = measure(it, styles).width
+  let widths = query(ref, loc).map(width)
+  let (first, second) = (width[Section~1], width[Section~2])
+  test(widths, (first, width[ibid.], second, first, width[ibid.]))
+}))
+
+---
+// Without ibid, repeated references are shown as usual.
+#set heading(numbering: "1.")
+
+= Introduction <intro>
+
+@intro and @intro.
+
+#locate(loc => style(styles => {
+  let widths = query(ref, loc).map(it => measure(it, styles).width)
+  test(widths, (measure[Section~1], measure[Section~1]).map(m => m.width))
+}))
+
+---
+// Ranges only repeat if they end at the same element.
+#set heading(numbering: "1.")
+#set ref(ibid: [ibid.])
+
+= Introduction <a>
+= Results <b>
+= Discussion <c>
+
+#ref(<a>, to: <b>), #ref(<a>, to: <c>), #ref(<a>, to: <c>), and @a.
+
+#locate(loc => style(styles => {
+  let width(it) = measure(it, styles).width
+  let widths = query(ref, loc).map(width)
+  let expected = ([Section~1–2], [Section~1–3], [ibid.], [Section~1]).map(width)
+  test(widths, expected)
+}))
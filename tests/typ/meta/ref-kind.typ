@@ -0,0 +1,68 @@
+// Test per-kind supplements and numberings for custom figure kinds.
+// Ref: false
+
+---
+// Theorems are told apart from other figures by their kind.
+#set ref(supplements: (theorem: [Theorem], figure: [Fig.]))
+
+#figure([A], kind: "theorem", supplement: [Thm.]) <a>
+#figure([B], kind: "theorem", supplement: [Thm.]) <b>
+#figure([C], caption: [C]) <c>
+
+@b and @c.
+
+#locate(loc => style(styles => {
+  let width(it) = measure(it, styles).width
+  test(query(ref, loc).map(width), (width[Theorem~2], width[Fig.~1]))
+}))
+
+---
+// Numberings only change the number in references.
+#set ref(numberings: (theorem: "I", figure: "a"))
+
+#figure([A], kind: "theorem", supplement: [Theorem]) <a>
+#figure([B], kind: "theorem", supplement: [Theorem]) <b>
+#figure([C], caption: [C]) <c>
+
+@b and @c.
+
+#locate(loc => style(styles => {
+  let width(it) = measure(it, styles).width
+  test(query(ref, loc).map(width), (width[Theorem~II], width[Figure~a]))
+  test(query(figure.where(kind: "theorem"), loc).map(it => it.numbering), ("1", "1"))
+}))
+
+---
+// Other kinds keep their own supplement and numbering.
+#set heading(numbering: "1.")
+#set ref(supplements: (theorem: [Theorem]), numberings: (theorem: "I"))
+
+= Introduction <intro>
+#figure([A], kind: "lemma", supplement: [Lemma]) <a>
+
+@intro and @a.
+
+#locate(loc => style(styles => {
+  let width(it) = measure(it, styles).width
+  test(query(ref, loc).map(width), (width[Section~1], width[Lemma~1]))
+}))
+
+---
+// Ranges use the numbering of the kind.
+#set ref(numberings: (theorem: "I"))
+
+#figure([A], kind: "theorem", supplement: [Theorem]) <a>
+#figure([B], kind: "theorem", supplement: [Theorem]) <b>
+
+#ref(<a>, to: <b>)
+
+#locate(loc => style(styles => {
+  let width(it) = measure(it, styles).width
+  test(query(ref, loc).map(width), (width[Theorem~I–II],))
+}))
+
+---
+// Error: 3:1-3:3 expected string or function, found integer
+#set ref(numberings: (theorem: 1))
+#figure([A], kind: "theorem", supplement: [Theorem]) <a>
+@a
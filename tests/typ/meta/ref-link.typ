@@ -0,0 +1,33 @@
+// Test references without links.
+// Ref: false
+
+---
+#set heading(numbering: "1.")
+#show ref: it => {
+  test(it.link, false)
+  it
+}
+
+= Introduction <intro>
+See #ref(<intro>, link: false).
+
+---
+#set heading(numbering: "1.")
+#set ref(link: false)
+#show ref: it => {
+  test(it.link, false)
+  it
+}
+
+= Introduction <intro>
+See @intro.
+
+---
+#show ref: it => {
+  test(it.link, true)
+  it
+}
+
+#set math.equation(numbering: "(1)")
+$ x $ <eq>
+See @eq.
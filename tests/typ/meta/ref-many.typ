@@ -0,0 +1,21 @@
+// Test that many references resolve to the right numbers.
+// Ref: false
+
+---
+#set heading(numbering: "1.")
+#show ref: it => locate(_ => {
+  let number = counter(heading).at(it.element.location()).first()
+  test(str(it.target), "s" + str(number - 1))
+  it
+})
+
+#for i in range(100) [#heading[Section] #label("s" + str(i))]
+#for i in range(100).rev() [See #ref(label("s" + str(i))).]
+
+---
+// Counters evaluated at many locations match their running values.
+#let c = counter("items")
+#for i in range(100) {
+  c.step()
+  locate(loc => test(c.at(loc), (i + 1,)))
+}
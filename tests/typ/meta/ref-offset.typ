@@ -0,0 +1,23 @@
+// Test offsetting the destination of a reference's link.
+// Ref: false
+
+---
+#set math.equation(numbering: "(1)")
+#show ref: it => {
+  test(it.offset, 1em)
+  it
+}
+
+$ a + b = c $ <sum>
+See #ref(<sum>, offset: 1em).
+
+---
+#set heading(numbering: "1.")
+#set ref(offset: 2pt)
+#show ref: it => {
+  test(it.offset, 2pt)
+  it
+}
+
+= Intro <intro>
+See @intro.
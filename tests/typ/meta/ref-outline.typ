@@ -0,0 +1,23 @@
+// Test references to outlines.
+// Ref: false
+
+---
+#show ref: it => locate(_ => {
+  test(it.element.func(), outline)
+  test(it.element.title.text, "Contents")
+  it
+})
+
+#outline() <toc>
+See @toc.
+
+= Introduction
+
+---
+#show ref: it => locate(_ => {
+  test(it.element.title, [List of Figures])
+  it
+})
+
+#outline(title: [List of Figures], target: figure) <lof>
+The figures are listed in @lof.
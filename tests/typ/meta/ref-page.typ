@@ -0,0 +1,24 @@
+// Test references with page numbers.
+// Ref: false
+
+---
+#set page(height: 80pt, numbering: "i")
+#set heading(numbering: "1.")
+#set ref(show-page: true)
+
+= Introduction <intro>
+#pagebreak()
+= Methods <methods>
+#pagebreak()
+See @intro, @methods, and #ref(<methods>, page-supplement: [page]).
+
+---
+// Pages without numbering fall back to arabic numerals, and an empty
+// supplement shows just the number.
+#set page(height: 80pt)
+#set math.equation(numbering: "(1)")
+#set ref(show-page: true, page-supplement: [])
+
+$ a + b = c $ <eq>
+#pagebreak()
+See @eq.
@@ -0,0 +1,91 @@
+// Test reference ranges.
+// Ref: false
+
+---
+// Ranges work with alphabetic numbering.
+#set heading(numbering: "A.", supplement: [Appendix])
+See #ref(<a>, to: <c>, supplement: [Appendices]) and #ref(<b>, to: <b>).
+
+= Data <a>
+= Code <b>
+= Proofs <c>
+
+---
+// The separator between the ends of a range is configurable.
+#set heading(numbering: "1.")
+#ref(<a>, to: <c>, range-separator: [-]) \
+#ref(<a>, to: <c>) \
+#ref(<a>, to: <c>, range-separator: [---]) \
+#set ref(range-separator: [ to ])
+#ref(<a>, to: <c>) and #ref(<b>, to: <b>)
+
+= Data <a>
+= Code <b>
+= Proofs <c>
+
+---
+#set heading(numbering: "1.")
+#set figure(numbering: "1")
+// Error: 2:2-2:25 cannot reference a range from heading to figure
+// Hint: 2:2-2:25 both ends of a range must be counted by the same counter
+= Intro <intro>
+#ref(<intro>, to: <fig>)
+#figure([A], caption: [B]) <fig>
+
+---
+#set heading(numbering: "A.")
+// Error: 3:2-3:19 reference range ends before it starts
+// Hint: 3:2-3:19 swap the start and the end of the range
+= First <a>
+= Second <b>
+#ref(<b>, to: <a>)
+
+---
+// Ranges follow the order in the document, even if the counter goes down.
+#set heading(numbering: "1.")
+#ref(<a>, to: <b>)
+
+#counter(heading).update(4)
+= Five <a>
+#counter(heading).update(1)
+= Two <b>
+
+---
+#set heading(numbering: "1.")
+#counter(heading).update(4)
+= Five <a>
+#counter(heading).update(1)
+= Two <b>
+
+// Error: 2-19 reference range ends before it starts
+// Hint: 2-19 swap the start and the end of the range
+#ref(<b>, to: <a>)
+
+---
+// Roman numerals aren't compared as text, so this range is valid even though
+// "v" sorts after "ix".
+#set heading(numbering: "i.", supplement: [Part])
+#ref(<v>, to: <ix>) and #ref(<ii>, to: <iv>)
+
+#for (i, name) in ("i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix").enumerate() [
+  #heading[Part #(i + 1)] #label(name)
+]
+
+---
+// Ranges may wrap after the supplement, but their numbers stay together.
+// Single numbers stay with their supplement.
+#set heading(numbering: "1.")
+
+#ref(<a>, to: <c>) \
+#ref(<a>, to: <c>, supplement: none) \
+@a
+
+= Data <a>
+= Code <b>
+= Proofs <c>
+
+#locate(loc => style(styles => {
+  let height(it) = measure(block(width: 1pt, it), styles).height
+  let (one, two) = (height[A], height[A \ B])
+  test(query(ref, loc).map(height), (two, one, one))
+}))
@@ -0,0 +1,51 @@
+// Test references to labelled lines of raw text.
+// Ref: false
+
+---
+#show ref: it => {
+  if it.element != none {
+    test(it.element.func(), raw.line)
+    test(it.element.number, 2)
+  }
+  it
+}
+
+#raw("let x = 1;\nx += 1;", block: true, labels: (incr: 2))
+The increment is on @incr.
+
+---
+#show ref: it => {
+  if it.element != none {
+    test(it.element.text, "c")
+  }
+  it
+}
+
+#raw("a\nb\nc", labels: (first: 1, last: 3))
+See @last.
+
+---
+// Error: 2-32 line 4 does not exist
+#raw("a\nb\nc", labels: (x: 4))
+
+---
+// Error: 2-32 line 0 does not exist
+#raw("a\nb\nc", labels: (x: 0))
+
+---
+// Labels can't be set for all raw elements.
+// Error: 10-24 unexpected argument: labels
+#set raw(labels: (x: 1))
+
+---
+// The supplement is the line's local name.
+#set text(lang: "de")
+#show ref: it => {
+  if it.element != none {
+    test(it.element.supplement, [Zeile])
+  }
+  it
+}
+
+#raw("a\nb", labels: (second: 2))
+Siehe @second.
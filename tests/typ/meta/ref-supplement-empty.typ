@@ -0,0 +1,19 @@
+// Test references with an empty supplement.
+// Ref: false
+
+---
+// Only the number is shown, without a leading space.
+#set heading(numbering: "1.")
+#set math.equation(numbering: "(1)")
+
+= Introduction <intro>
+#figure([A], caption: [B]) <fig>
+$ x = y $ <eq>
+
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  test(width[@intro[].], width[1.])
+  test(width[@fig[].], width[1.])
+  test(width[@eq[].], width[(1).])
+  test(width(ref(<intro>, supplement: none)), width[1])
+})
@@ -0,0 +1,29 @@
+// Test the font style of reference supplements.
+// Ref: false
+
+---
+#set heading(numbering: "1.")
+
+= Introduction <intro>
+
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  let upright = emph[#text(style: "normal")[Section]~1]
+  test(width(emph[Section]) == width[Section], false)
+  test(width(emph(ref(<intro>))), width(emph[Section~1]))
+  test(width(emph(ref(<intro>, supplement-style: "normal"))), width(upright))
+  test(width(ref(<intro>, supplement-style: "italic")), width[_Section_~1])
+})
+
+---
+// The style only applies to the supplement, not to the number.
+#set heading(numbering: "1.")
+#set ref(supplement-style: "normal")
+
+= Introduction <intro>
+
+#style(styles => {
+  let width(it) = measure(it, styles).width
+  test(width(emph(ref(<intro>, supplement: none))), width(emph[1]))
+  test(width(emph[@intro]), width(emph[#text(style: "normal")[Section]~1]))
+})
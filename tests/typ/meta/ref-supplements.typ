@@ -0,0 +1,41 @@
+// Test per-kind default supplements for references.
+// Ref: false
+
+---
+#set heading(numbering: "1.")
+#set ref(supplements: (figure: "Fig.", heading: [Sec.]))
+#show ref: it => locate(_ => {
+  test(it.supplements.len(), 2)
+  it
+})
+
+= Introduction <intro>
+#figure([A], caption: [B]) <fig>
+See @intro and @fig.
+
+---
+// An explicit supplement takes precedence over the map.
+#set heading(numbering: "1.")
+#set ref(supplements: (heading: "Sec."))
+= Introduction <intro>
+See @intro[Chapter].
+
+---
+// Functions and none are allowed as values.
+#set heading(numbering: "1.")
+#set math.equation(numbering: "(1)")
+#set ref(supplements: (
+  heading: it => if it.level == 1 [Chapter] else [Section],
+  equation: none,
+))
+
+= Introduction <intro>
+$ a = b $ <eq>
+See @intro and @eq.
+
+---
+// Error: 4:5-4:11 expected none, content, or function, found integer
+#set heading(numbering: "1.")
+#set ref(supplements: (heading: 1))
+= Introduction <intro>
+See @intro.
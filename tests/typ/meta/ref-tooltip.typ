@@ -0,0 +1,46 @@
+// Test tooltips derived from the referenced element.
+// Ref: false
+
+---
+#set heading(numbering: "1.")
+#set ref(tooltip: true)
+#show ref: it => locate(_ => {
+  test(it.alt, "Section 1: Introduction")
+  it
+})
+
+= Introduction <intro>
+See @intro.
+
+---
+#set ref(tooltip: true)
+#show ref: it => locate(_ => {
+  test(it.alt, "Figure 1: A glacier")
+  it
+})
+
+#figure([Body], caption: [A glacier]) <fig>
+See @fig.
+
+---
+// An explicit alternative description takes precedence.
+#set heading(numbering: "1.")
+#set ref(tooltip: true)
+#show ref: it => locate(_ => {
+  test(it.alt, "Jump")
+  it
+})
+
+= Introduction <intro>
+#ref(<intro>, alt: "Jump")
+
+---
+// Tooltips are disabled by default.
+#set heading(numbering: "1.")
+#show ref: it => locate(_ => {
+  test(it.alt, none)
+  it
+})
+
+= Introduction <intro>
+See @intro.
@@ -0,0 +1,90 @@
+// Test the resolution and return values of supplement functions.
+// Ref: false
+
+---
+#set heading(numbering: "1.", supplement: it => {
+  if it.level == 1 [Chapter] else [Section]
+})
+
+= Introduction <intro>
+== Motivation <motivation>
+== Scope <scope>
+= Results <results>
+
+See @intro, @motivation, @scope, and @results.
+
+#locate(loc => {
+  let supplements = query(heading, loc).map(it => it.supplement)
+  test(supplements, ([Chapter], [Section], [Section], [Chapter]))
+})
+
+---
+#set heading(numbering: "1.")
+= Intro <intro>
+#ref(<intro>, supplement: it => [Chapter])
+#ref(<intro>, supplement: it => "Section")
+
+---
+#set heading(numbering: "1.")
+= Intro <intro>
+
+// Error: 2-43 expected content, found function
+// Hint: 2-43 the supplement function must return content or text
+#ref(<intro>, supplement: it => it.func())
+
+---
+// Extra parameters are fine if they have defaults.
+#set heading(numbering: "1.")
+= Intro <intro>
+#ref(<intro>, supplement: (it, prefix: [Sec.]) => prefix)
+#ref(<intro>, supplement: (it, ..rest) => [Section])
+
+---
+#set heading(numbering: "1.")
+= Intro <intro>
+
+// Error: 27-46 missing argument: b
+// Hint: 27-46 supplement functions are called with the element as their only argument
+#ref(<intro>, supplement: (a, b) => [Chapter])
+
+---
+#set heading(numbering: "1.")
+= Intro <intro>
+
+// Error: 27-35 unexpected argument
+// Hint: 27-35 supplement functions are called with the element as their only argument
+#ref(<intro>, supplement: () => [])
+
+---
+#set heading(numbering: "1.")
+= Intro <intro>
+
+// Error: 27-54 missing argument: b
+// Hint: 27-54 supplement functions are called with the element as their only argument
+#ref(<intro>, supplement: (a, b, ..rest) => [Chapter])
+
+---
+// Errors from within the function keep their span.
+#set heading(numbering: "1.")
+= Intro <intro>
+
+#let f(a, b) = a
+// Error: 34-38 missing argument: b
+#ref(<intro>, supplement: it => f(it))
+
+---
+// Error: 2-36 expected content, found length
+// Hint: 2-36 the supplement function must return content or text
+#figure([A], supplement: it => 1pt)
+
+---
+#set heading(numbering: "1.", supplement: it => (1, 2))
+// Error: 1-8 expected content, found array
+// Hint: 1-8 the supplement function must return content or text
+= Intro
+
+---
+#set math.equation(numbering: "(1)", supplement: it => (a: 1))
+// Error: 1-6 expected content, found dictionary
+// Hint: 1-6 the supplement function must return content or text
+$ x $